@@ -0,0 +1,72 @@
+//! Measures how fast values are converted, by the library and by the CLI on a large input.
+//!
+//! Run with `cargo bench`, which builds the CLI in release mode.
+
+#![expect(clippy::print_stdout, reason = "reports the results")]
+#![expect(clippy::expect_used, reason = "benchmarks should fail")]
+
+use std::fs::{self, File};
+use std::hint::black_box;
+use std::process::{self, Command, Stdio};
+use std::time::{Duration, Instant};
+use std::{env, io};
+
+use caseify::Case;
+
+/// Size of the generated input, in bytes.
+const INPUT_SIZE: usize = 256 * 1024 * 1024;
+
+/// Identifiers the input is made of, in various cases.
+const VALUES: &[&str] = &[
+    "fooBarBaz",
+    "HTTPRequestHandler",
+    "snake_case_value",
+    "SCREAMING_SNAKE_VALUE",
+    "kebab-case-value",
+    "Some Sentence with words",
+    "dot.case.value",
+    "éléphantRose",
+];
+
+/// Prints the throughput of a run, in MB/s.
+fn report(name: &str, bytes: usize, elapsed: Duration) {
+    #[expect(clippy::cast_precision_loss, reason = "approximate")]
+    let megabytes = bytes as f64 / 1_000_000.0;
+    println!(
+        "{name}: {megabytes:.0} MB in {:.2}s, {:.0} MB/s",
+        elapsed.as_secs_f64(),
+        megabytes / elapsed.as_secs_f64()
+    );
+}
+
+fn main() {
+    let mut input = String::with_capacity(INPUT_SIZE);
+    for value in VALUES.iter().cycle() {
+        if input.len() >= INPUT_SIZE {
+            break;
+        }
+        input.push_str(value);
+        input.push('\n');
+    }
+
+    let start = Instant::now();
+    for line in input.lines() {
+        black_box(Case::Snake.caseify(black_box(line)));
+    }
+    report("library", input.len(), start.elapsed());
+
+    let path = env::temp_dir().join(format!("caseify-bench-{}", process::id()));
+    fs::write(&path, &input).expect("Failed to write the input");
+    let start = Instant::now();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_caseify"))
+        .arg("Snake")
+        .stdin(File::open(&path).expect("Failed to open the input"))
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+    let mut stdout = child.stdout.take().expect("Failed to open stdout");
+    io::copy(&mut stdout, &mut io::sink()).expect("Failed to read stdout");
+    assert!(child.wait().expect("Failed to wait").success());
+    report("cli", input.len(), start.elapsed());
+    fs::remove_file(&path).expect("Failed to remove the input");
+}
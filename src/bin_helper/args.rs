@@ -0,0 +1,95 @@
+/// Argument of the command line, once split into options and values.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Arg {
+    /// Long option, like `--snake` or `--case=snake`, without the leading dashes.
+    Long(String, Option<String>),
+    /// Short option, like `-s`.
+    Short(char),
+    /// Positional argument, like a case or a value.
+    Value(String),
+}
+
+impl Arg {
+    /// Returns the positional argument, or `None` if the argument is an option.
+    pub fn into_value(self) -> Option<String> {
+        match self {
+            Self::Value(value) => Some(value),
+            Self::Long(..) | Self::Short(_) => None,
+        }
+    }
+
+    /// Returns `true` iff the argument asks for the help message.
+    pub fn is_help(&self) -> bool {
+        match self {
+            Self::Long(name, None) => name == "help",
+            Self::Short(short) => *short == 'h',
+            Self::Long(_, Some(_)) | Self::Value(_) => false,
+        }
+    }
+}
+
+/// Splits the arguments into options and values.
+///
+/// Short options can be grouped (`-sh` is `-s -h`), a lone `-` is a value and every argument after
+/// `--` is a value, even if it starts with a dash.
+pub fn lex<Args: Iterator<Item = String>>(args: Args) -> Vec<Arg> {
+    let mut lexed = vec![];
+    let mut only_values = false;
+    for arg in args {
+        if only_values || arg == "-" {
+            lexed.push(Arg::Value(arg));
+        } else if arg == "--" {
+            only_values = true;
+        } else if let Some(long) = arg.strip_prefix("--") {
+            lexed.push(match long.split_once('=') {
+                Some((name, value)) => Arg::Long(name.to_owned(), Some(value.to_owned())),
+                None => Arg::Long(long.to_owned(), None),
+            });
+        } else if let Some(shorts) = arg.strip_prefix('-') {
+            lexed.extend(shorts.chars().map(Arg::Short));
+        } else {
+            lexed.push(Arg::Value(arg));
+        }
+    }
+    lexed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arg, lex};
+
+    fn test(args: &[&str], expected: &[Arg]) {
+        assert_eq!(lex(args.iter().map(|arg| (*arg).to_owned())), expected);
+    }
+
+    #[test]
+    fn options() {
+        test(
+            &["--snake", "-s", "--case=kebab", "--case", "value"],
+            &[
+                Arg::Long("snake".to_owned(), None),
+                Arg::Short('s'),
+                Arg::Long("case".to_owned(), Some("kebab".to_owned())),
+                Arg::Long("case".to_owned(), None),
+                Arg::Value("value".to_owned()),
+            ],
+        );
+    }
+
+    #[test]
+    fn grouped_shorts() {
+        test(&["-sh"], &[Arg::Short('s'), Arg::Short('h')]);
+    }
+
+    #[test]
+    fn end_of_options() {
+        test(
+            &["-", "--", "--snake", "-s"],
+            &[
+                Arg::Value("-".to_owned()),
+                Arg::Value("--snake".to_owned()),
+                Arg::Value("-s".to_owned()),
+            ],
+        );
+    }
+}
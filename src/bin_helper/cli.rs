@@ -1,76 +1,1162 @@
-use std::io::{self, BufRead as _};
+use core::iter::Peekable;
+use core::num::NonZeroUsize;
+use core::str::FromStr;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
+use crate::bin_helper::args::{self, Arg};
+use crate::bin_helper::code::Code;
+use crate::bin_helper::fields::Fields;
+use crate::bin_helper::ini::{self, Dialect};
+use crate::bin_helper::json::{Json, Layout};
+use crate::bin_helper::lexer::Language;
+use crate::bin_helper::lint::Lint;
+use crate::bin_helper::pattern::Pattern;
+use crate::bin_helper::records::{self, Delimiter, Ending, Invalid, Record, Records, Text};
 use crate::bin_helper::status::Status;
-use crate::parser::case::Case;
+use crate::bin_helper::{files, parallel, rename, suggest, toml, yaml};
+use crate::parser::case::{Case, ParseCaseError};
+use crate::parser::case_cycle::CaseCycle;
+use crate::parser::convention::{Convention, IdentKind, ParseConventionError, ParseIdentKindError};
+
+/// Commands that take the case as their first argument, like `caseify check Snake`.
+const CASE_COMMANDS: &[&str] = &[
+    "check", "code", "env", "ini", "json", "rename", "toml", "yaml",
+];
+
+/// Names of the conventions accepted by `convention`, for its error messages.
+const CONVENTIONS: &str =
+    "`c`, `csharp`, `go`, `java`, `javascript`, `kotlin`, `python`, `rust`, `sql` or `swift`";
+
+/// Names of the kinds of identifiers accepted by `convention` and `--kind`, for their error
+/// messages.
+const KINDS: &str =
+    "`constant`, `enum-variant`, `field`, `file-name`, `function`, `local`, `module` or `type`";
+
+/// Short options to select a case.
+pub const SHORT_OPTIONS: &[(char, Case)] = &[
+    ('c', Case::Camel),
+    ('t', Case::Capitalised),
+    ('C', Case::Constant),
+    ('v', Case::CssVar),
+    ('d', Case::Dot),
+    ('k', Case::Kebab),
+    ('p', Case::Pascal),
+    ('S', Case::Sentence),
+    ('s', Case::Snake),
+];
+
+/// Action to perform on the values.
+#[derive(Debug)]
+enum Action {
+    /// Reports the values that aren't in the given case.
+    Check(Case),
+    /// Converts the identifiers of source files.
+    Code(Code),
+    /// Converts the values to the case of a kind of identifiers in a naming convention.
+    Convention {
+        /// Naming convention of the language.
+        convention: Convention,
+        /// Kind of identifiers the values are.
+        kind: IdentKind,
+    },
+    /// Converts the values to the given case.
+    Convert(Case),
+    /// Converts the values to the next case of the default [`CaseCycle`].
+    Cycle(CaseCycle),
+    /// Prints the cases the values are already in, tab-separated if `porcelain` is set.
+    Detect {
+        /// Whether to print machine-readable output.
+        porcelain: bool,
+    },
+    /// Converts the keys of `.env` files.
+    Env(Case),
+    /// Converts the keys of INI and properties files.
+    Ini(Case),
+    /// Converts the keys of JSON documents.
+    Json(Json),
+    /// Reports the names of the source files that break the convention of their language.
+    Lint(Lint),
+    /// Renames the files and directories given as values to the given case.
+    Rename {
+        /// Case to rename the files and directories to.
+        case: Case,
+        /// Whether to only print the renames, without performing them.
+        dry_run: bool,
+        /// Whether to also rename the contents of the directories.
+        recursive: bool,
+    },
+    /// Converts the keys of TOML documents.
+    Toml(Case),
+    /// Converts the keys of YAML documents.
+    Yaml(Case),
+}
+
+impl Action {
+    /// Applies the action to the value and returns the output.
+    ///
+    /// When checking, the output is the fix of the value, and for documents, the converted key.
+    /// Values are unchanged for `lint`, which only reads source files.
+    fn apply(&self, value: &str) -> String {
+        match self {
+            Self::Check(case)
+            | Self::Code(Code { case, .. })
+            | Self::Convert(case)
+            | Self::Env(case)
+            | Self::Ini(case)
+            | Self::Json(Json { case, .. })
+            | Self::Rename { case, .. }
+            | Self::Toml(case)
+            | Self::Yaml(case) => case.caseify(value),
+            Self::Convention { convention, kind } => convention.convert(*kind, value),
+            Self::Cycle(cycle) => cycle.cycle_next(value),
+            Self::Detect { porcelain } => detect(value, *porcelain),
+            Self::Lint(_) => value.to_owned(),
+        }
+    }
+
+    /// Checks that the flags apply to the action, and passes it the ones it needs.
+    fn configure(&mut self, flags: &Flags) -> Result<(), Status> {
+        let in_place = matches!(flags.source, Source::InPlace(_));
+        let misused = self.misused_document_flags(flags).or_else(|| match self {
+            Self::Check(_) if in_place => Some("`check` can't edit files in place"),
+            Self::Check(_) if flags.invalid == Invalid::Pass => {
+                Some("`check` can't pass invalid UTF-8 through, use `--invalid=error` or `lossy`")
+            }
+            Self::Detect { .. } if in_place => Some("`detect` can't edit files in place"),
+            Self::Rename { .. } if flags.source != Source::Args || flags.join => {
+                Some("`rename` only takes paths, without `--files`, `--in-place` or `--join`")
+            }
+            Self::Lint(_) if flags.source != Source::Args || flags.join => {
+                Some("`lint` only takes paths, without `--files`, `--in-place` or `--join`")
+            }
+            Self::Check(_)
+            | Self::Code(_)
+            | Self::Convention { .. }
+            | Self::Convert(_)
+            | Self::Cycle(_)
+            | Self::Env(_)
+            | Self::Ini(_)
+            | Self::Json(_)
+            | Self::Lint(_)
+            | Self::Rename { .. }
+            | Self::Toml(_)
+            | Self::Yaml(_)
+                if flags.porcelain =>
+            {
+                Some("`--porcelain` can only be used with `detect`")
+            }
+            Self::Check(_)
+            | Self::Code(_)
+            | Self::Convention { .. }
+            | Self::Convert(_)
+            | Self::Cycle(_)
+            | Self::Detect { .. }
+            | Self::Env(_)
+            | Self::Ini(_)
+            | Self::Json(_)
+            | Self::Lint(_)
+            | Self::Toml(_)
+            | Self::Yaml(_)
+                if flags.dry_run || flags.recursive =>
+            {
+                Some("`--dry-run` and `--recursive` can only be used with `rename`")
+            }
+            Self::Check(_) | Self::Detect { .. } | Self::Lint(_) | Self::Rename { .. }
+                if flags.selects_fields() =>
+            {
+                Some(
+                    "`--fields`, `--separator`, `--csv` and `--header-only` can only be used to convert",
+                )
+            }
+            Self::Check(_) | Self::Detect { .. } | Self::Lint(_) | Self::Rename { .. }
+                if flags.pattern.is_some() =>
+            {
+                Some("`--match` can only be used to convert")
+            }
+            Self::Check(_) | Self::Lint(_) | Self::Rename { .. } if flags.jobs.is_some() => {
+                Some("`--jobs` can only be used to convert or detect")
+            }
+            Self::Code(code) => {
+                code.kinds.clone_from(&flags.kinds);
+                code.language = flags.language;
+                code.pattern.clone_from(&flags.pattern);
+                None
+            }
+            Self::Detect { porcelain } => {
+                *porcelain = flags.porcelain;
+                None
+            }
+            Self::Json(json) => {
+                json.depth = flags.depth;
+                json.exclude.clone_from(&flags.exclude);
+                json.include.clone_from(&flags.include);
+                json.layout = flags.layout.unwrap_or_default();
+                json.values = flags.values;
+                None
+            }
+            Self::Lint(lint) => {
+                lint.fix = flags.fix;
+                lint.kinds.clone_from(&flags.kinds);
+                None
+            }
+            Self::Rename {
+                dry_run, recursive, ..
+            } => {
+                *dry_run = flags.dry_run;
+                *recursive = flags.recursive;
+                None
+            }
+            Self::Check(_)
+            | Self::Convention { .. }
+            | Self::Convert(_)
+            | Self::Cycle(_)
+            | Self::Env(_)
+            | Self::Ini(_)
+            | Self::Toml(_)
+            | Self::Yaml(_) => None,
+        });
+        misused.map_or(Ok(()), |err| Err(Status::Usage(err.to_owned())))
+    }
+
+    /// Returns why the flags don't apply to the documents of `code`, `env`, `ini`, `json`, `toml`
+    /// and `yaml`, or why the options of `code`, `json` and `lint` don't apply to the other
+    /// actions, if they don't.
+    fn misused_document_flags(&self, flags: &Flags) -> Option<&'static str> {
+        let is_document = matches!(
+            self,
+            Self::Env(_) | Self::Ini(_) | Self::Json(_) | Self::Toml(_) | Self::Yaml(_)
+        );
+        let reads_records = flags.join
+            || flags.jobs.is_some()
+            || flags.selects_fields()
+            || flags.delimiter != Delimiter::Lines
+            || flags.invalid != Invalid::Error;
+        if flags.sets_json() && !matches!(self, Self::Json(_)) {
+            Some(
+                "`--depth`, `--include`, `--exclude`, `--values`, `--pretty` and `--compact` can only be used with `json`",
+            )
+        } else if flags.language.is_some() && !matches!(self, Self::Code(_)) {
+            Some("`--lang` can only be used with `code`")
+        } else if !flags.kinds.is_empty() && !matches!(self, Self::Code(_) | Self::Lint(_)) {
+            Some("`--kind` can only be used with `code` and `lint`")
+        } else if flags.fix && !matches!(self, Self::Lint(_)) {
+            Some("`--fix` can only be used with `lint`")
+        } else if matches!(self, Self::Code(_)) && reads_records {
+            Some("`code` reads whole sources, without `--join`, `--jobs`, record or field options")
+        } else if is_document && (reads_records || flags.pattern.is_some()) {
+            Some(
+                "`env`, `ini`, `json`, `toml` and `yaml` read whole documents, without `--join`, `--jobs`, `--match`, record or field options",
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns the command of [`CASE_COMMANDS`] with the given name, applied to the case.
+    fn with_case(command: &str, case: Case) -> Self {
+        match command {
+            "code" => Self::Code(Code::new(case)),
+            "env" => Self::Env(case),
+            "ini" => Self::Ini(case),
+            "json" => Self::Json(Json::new(case)),
+            "rename" => Self::Rename {
+                case,
+                dry_run: false,
+                recursive: false,
+            },
+            "toml" => Self::Toml(case),
+            "yaml" => Self::Yaml(case),
+            _ => Self::Check(case),
+        }
+    }
+}
+
+/// Options that don't select the action.
+#[derive(Debug, Default)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "independent command line flags"
+)]
+struct Flags {
+    /// Whether the fields are read as CSV (`--csv`).
+    csv: bool,
+    /// Separator of the records of stdin and files (`-0`, `--null`, `--delimiter`).
+    delimiter: Delimiter,
+    /// Deepest level of the JSON objects whose keys are converted (`--depth`).
+    depth: Option<usize>,
+    /// Whether to only print the renames (`-n`, `--dry-run`).
+    dry_run: bool,
+    /// Patterns of the JSON keys that aren't converted (`--exclude`).
+    exclude: Vec<Pattern>,
+    /// Numbers of the fields to convert (`--field`, `--fields`).
+    fields: Vec<usize>,
+    /// Whether to fix the names that break the convention (`--fix`).
+    fix: bool,
+    /// Whether to only convert the first record (`--header-only`).
+    header_only: bool,
+    /// Patterns of the only JSON keys that are converted (`--include`).
+    include: Vec<Pattern>,
+    /// How to handle the records that aren't valid UTF-8 (`--invalid`).
+    invalid: Invalid,
+    /// Number of threads to convert the records on (`--jobs`).
+    jobs: Option<NonZeroUsize>,
+    /// Whether to join the values with spaces (`-j`, `--join`).
+    join: bool,
+    /// Kinds of the only identifiers of source files that are converted (`--kind`).
+    kinds: Vec<IdentKind>,
+    /// Language of the source files (`--lang`).
+    language: Option<Language>,
+    /// Layout of the JSON output (`--pretty`, `--compact`).
+    layout: Option<Layout>,
+    /// Tokens to convert in free text, instead of the whole records (`--match`).
+    pattern: Option<Pattern>,
+    /// Whether to print machine-readable output (`--porcelain`).
+    porcelain: bool,
+    /// Whether to rename the contents of directories (`-r`, `--recursive`).
+    recursive: bool,
+    /// Separator of the fields (`--separator`).
+    separator: Option<String>,
+    /// Where the values are read from (`-f`, `--files`, `-i`, `--in-place`).
+    source: Source,
+    /// Whether to also convert the JSON string values (`--values`).
+    values: bool,
+}
+
+impl Flags {
+    /// Returns the fields to convert in each record, if any option selects them.
+    ///
+    /// The fields are separated by tabs by default, or by commas in CSV mode, and they are all
+    /// converted if none is selected.
+    fn fields(&self) -> Option<Fields> {
+        self.selects_fields().then(|| Fields {
+            csv: self.csv,
+            header_only: self.header_only,
+            selected: self.fields.clone(),
+            separator: self
+                .separator
+                .clone()
+                .unwrap_or_else(|| if self.csv { "," } else { "\t" }.to_owned()),
+        })
+    }
+
+    /// Returns `true` iff an option selects the fields to convert.
+    const fn selects_fields(&self) -> bool {
+        self.csv || self.header_only || !self.fields.is_empty() || self.separator.is_some()
+    }
+
+    /// Sets the flag given by the argument, and returns `false` if the argument isn't a flag.
+    ///
+    /// The value of the flag is taken from the following arguments if needed.
+    fn set<Lexed: Iterator<Item = Arg>>(
+        &mut self,
+        arg: &Arg,
+        lexed: &mut Peekable<Lexed>,
+    ) -> Result<bool, Status> {
+        match arg {
+            Arg::Short('0') => self.delimiter = Delimiter::Custom("\0".to_owned()),
+            Arg::Short('f') => self.source = self.source.with_files(),
+            Arg::Short('i') => self.source = Source::InPlace(None),
+            Arg::Short('j') => self.join = true,
+            Arg::Short('n') => self.dry_run = true,
+            Arg::Short('r') => self.recursive = true,
+            Arg::Long(name, suffix) if name == "in-place" => {
+                self.source = Source::InPlace(suffix.clone());
+            }
+            Arg::Long(name, value) if name == "delimiter" => {
+                let delimiter = option_value(name, value.clone(), lexed)?;
+                if delimiter.is_empty() {
+                    return Err(Status::Usage("`--delimiter` can't be empty".to_owned()));
+                }
+                self.delimiter = Delimiter::Custom(delimiter);
+            }
+            Arg::Long(name, value) if name == "depth" => {
+                let depth = option_value(name, value.clone(), lexed)?;
+                self.depth = Some(depth.parse().map_err(|_invalid| {
+                    Status::Usage(format!(
+                        "Invalid value `{depth}` for `--depth`, expected a number"
+                    ))
+                })?);
+            }
+            Arg::Long(name, value) if name == "exclude" || name == "include" => {
+                let glob = option_value(name, value.clone(), lexed)?;
+                let pattern = Pattern::Glob(glob.chars().collect());
+                if name == "exclude" {
+                    self.exclude.push(pattern);
+                } else {
+                    self.include.push(pattern);
+                }
+            }
+            Arg::Long(name, value) if matches!(name.as_str(), "field" | "fields" | "kind") => {
+                let list = option_value(name, value.clone(), lexed)?;
+                self.set_list(name, &list)?;
+            }
+            Arg::Long(name, value) if name == "invalid" => {
+                self.invalid = parse_value(name, value.clone(), lexed)?;
+            }
+            Arg::Long(name, value) if name == "jobs" => {
+                let jobs = option_value(name, value.clone(), lexed)?;
+                self.jobs = Some(jobs.parse().map_err(|_invalid| {
+                    Status::Usage(format!(
+                        "Invalid value `{jobs}` for `--jobs`, expected a positive number"
+                    ))
+                })?);
+            }
+            Arg::Long(name, value) if name == "lang" => {
+                self.language = Some(parse_value(name, value.clone(), lexed)?);
+            }
+            Arg::Long(name, glob) if name == "match" => {
+                let following = lexed.next_if(|next| {
+                    glob.is_none()
+                        && matches!(next, Arg::Value(value) if value.contains(['*', '?']))
+                });
+                self.pattern = Some(match (glob.clone(), following) {
+                    (Some(empty), _) if empty.is_empty() => {
+                        return Err(Status::Usage("`--match` can't be empty".to_owned()));
+                    }
+                    (Some(pattern), _) | (None, Some(Arg::Value(pattern))) => {
+                        Pattern::Glob(pattern.chars().collect())
+                    }
+                    (None, _) => Pattern::Identifiers,
+                });
+            }
+            Arg::Long(name, value) if name == "separator" => {
+                let separator = option_value(name, value.clone(), lexed)?;
+                if separator.is_empty() {
+                    return Err(Status::Usage("`--separator` can't be empty".to_owned()));
+                }
+                self.separator = Some(separator);
+            }
+            Arg::Long(name, value) => {
+                match name.as_str() {
+                    "compact" => self.layout = Some(Layout::Compact),
+                    "csv" => self.csv = true,
+                    "dry-run" => self.dry_run = true,
+                    "files" => self.source = self.source.with_files(),
+                    "header-only" => self.header_only = true,
+                    "fix" => self.fix = true,
+                    "join" => self.join = true,
+                    "null" => self.delimiter = Delimiter::Custom("\0".to_owned()),
+                    "porcelain" => self.porcelain = true,
+                    "pretty" => self.layout = Some(Layout::Pretty),
+                    "recursive" => self.recursive = true,
+                    "values" => self.values = true,
+                    _ => return Ok(false),
+                }
+                if value.is_some() {
+                    return Err(Status::Usage(format!("`--{name}` doesn't take a value")));
+                }
+            }
+            Arg::Short(_) | Arg::Value(_) => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Sets an option taking a comma-separated list, `--fields` or `--kind`.
+    fn set_list(&mut self, name: &str, list: &str) -> Result<(), Status> {
+        for item in list.split(',') {
+            if name == "kind" {
+                self.kinds
+                    .push(item.parse().map_err(|_invalid: ParseIdentKindError| {
+                        Status::Usage(format!(
+                            "Invalid value `{item}` for `--kind`, expected {KINDS}"
+                        ))
+                    })?);
+            } else {
+                let number = item.parse::<NonZeroUsize>().map_err(|_invalid| {
+                    Status::Usage(format!(
+                        "Invalid value `{list}` for `--{name}`, expected field numbers like `1,3`"
+                    ))
+                })?;
+                self.fields.push(number.get());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` iff an option of the `json` command is set.
+    const fn sets_json(&self) -> bool {
+        self.depth.is_some()
+            || !self.exclude.is_empty()
+            || !self.include.is_empty()
+            || self.layout.is_some()
+            || self.values
+    }
+}
+
+/// Where the values are read from, and where the results are written to.
+#[derive(Debug, Default, PartialEq, Eq)]
+enum Source {
+    /// The values are given on the command line, or read from stdin if there are none.
+    #[default]
+    Args,
+    /// The values are paths of files whose lines are converted and printed.
+    Files,
+    /// The values are paths of files whose lines are converted and written back, with an optional
+    /// backup suffix.
+    InPlace(Option<String>),
+}
+
+impl Source {
+    /// Reads the values as files, unless they are already edited in place.
+    fn with_files(&self) -> Self {
+        match self {
+            Self::Args | Self::Files => Self::Files,
+            Self::InPlace(suffix) => Self::InPlace(suffix.clone()),
+        }
+    }
+}
 
 /// Options passed through the command line
 #[derive(Debug)]
 pub struct Cli {
-    /// Case to convert to
-    case: Case,
+    /// Action to perform on the values
+    action: Action,
+    /// Separator of the records of stdin and files, also written after each output.
+    delimiter: Delimiter,
+    /// Fields of each record to convert, instead of the whole records.
+    fields: Option<Fields>,
+    /// How to handle the records of stdin and files that aren't valid UTF-8.
+    invalid: Invalid,
+    /// Number of threads to convert the records of stdin and files on, if more than the main one.
+    jobs: Option<NonZeroUsize>,
+    /// Whether the values are joined with spaces into a single phrase, instead of being converted
+    /// separately.
+    join: bool,
+    /// Tokens to convert in free text, instead of the whole records or fields.
+    pattern: Option<Pattern>,
+    /// Where the values are read from.
+    source: Source,
     /// If no value is provided, reads from stdin (e.g. for pipes).
-    value: Option<String>,
+    values: Vec<String>,
 }
 
 impl Cli {
-    /// Parses the inputs [`Args`] into a [`Cli`] by checking the validity of the [`Args`].
-    pub fn parse<Args: Iterator<Item = String>>(mut args: Args) -> Result<Self, Status> {
-        let case = Self::parse_case(args.next())?;
+    /// Applies the action to the value, or to its selected fields, where the value is the record
+    /// with the given number.
+    ///
+    /// With a pattern, only the matching tokens of the value or fields are converted.
+    fn convert(&self, number: usize, value: &str) -> String {
+        let apply = |text: &str| {
+            self.pattern.as_ref().map_or_else(
+                || self.action.apply(text),
+                |pattern| pattern.replace(text, |token| self.action.apply(token)),
+            )
+        };
+        self.fields.as_ref().map_or_else(
+            || apply(value),
+            |fields| fields.convert(number, value, apply),
+        )
+    }
+
+    /// Converts the records of the reader and writes them, on several threads with `--jobs`, the
+    /// keys of its documents with `env`, `ini`, `json`, `toml` and `yaml`, or the identifiers of
+    /// its source with `code`.
+    fn convert_records<R: Read + Send, W: Write>(
+        &self,
+        origin: &str,
+        reader: R,
+        out: &mut W,
+    ) -> io::Result<()> {
+        match &self.action {
+            Action::Code(code) => return code.recase(origin, reader, out),
+            Action::Env(case) => return recase_keys(origin, Dialect::Env, *case, reader, out),
+            Action::Ini(case) => return recase_keys(origin, Dialect::Ini, *case, reader, out),
+            Action::Json(json) => {
+                warn_collisions(origin, json.recase(BufReader::new(reader), out)?);
+                return Ok(());
+            }
+            Action::Toml(case) => return toml::recase(*case, reader, out),
+            Action::Yaml(case) => return yaml::recase(*case, reader, out),
+            Action::Check(_)
+            | Action::Convention { .. }
+            | Action::Convert(_)
+            | Action::Cycle(_)
+            | Action::Detect { .. }
+            | Action::Lint(_)
+            | Action::Rename { .. } => (),
+        }
+        let Some(jobs) = self.jobs else {
+            return self.for_each_record(origin, BufReader::new(reader), |number, text, record| {
+                self.write_record(out, number, text, record)
+            });
+        };
+        let end = self.delimiter.end()?;
+        let quoted = self.is_quoted();
+        let complete = |bytes: &[u8]| records::complete(bytes, end, quoted);
+        parallel::convert(jobs, reader, &complete, out, &|first, chunk| {
+            let mut converted = vec![];
+            self.for_each_record_from(origin, first, chunk, |number, text, record| {
+                self.write_record(&mut converted, number, text, record)
+            })?;
+            Ok(converted)
+        })
+    }
+
+    /// Calls `each` on every record of the reader, with its number and its text, like
+    /// [`Cli::for_each_record_from`] from the first record.
+    fn for_each_record<
+        R: BufRead,
+        Each: FnMut(usize, Option<&str>, &Record<'_>) -> io::Result<()>,
+    >(
+        &self,
+        origin: &str,
+        reader: R,
+        each: Each,
+    ) -> io::Result<()> {
+        self.for_each_record_from(origin, 1, reader, each)
+    }
+
+    /// Calls `each` on every record of the reader, with its number and its text, counting from
+    /// `first`.
+    ///
+    /// The text is `None` for the records that aren't valid UTF-8 but are passed through. The
+    /// invalid records are reported with their number, as warnings or as an error depending on
+    /// how they are handled.
+    #[expect(clippy::print_stderr, reason = "this is a CLI")]
+    fn for_each_record_from<
+        R: BufRead,
+        Each: FnMut(usize, Option<&str>, &Record<'_>) -> io::Result<()>,
+    >(
+        &self,
+        origin: &str,
+        first: usize,
+        reader: R,
+        mut each: Each,
+    ) -> io::Result<()> {
+        let record_name = self.delimiter.record_name();
+        for (number, record) in
+            (first..).zip(Records::new(reader, &self.delimiter, self.is_quoted()))
+        {
+            let read = record?;
+            let text = read.text(self.invalid).map_err(|err| {
+                io::Error::new(err.kind(), format!("{record_name} {number}: {err}"))
+            })?;
+            let warn = |handling: &str| {
+                eprintln!(
+                    "Warning: {origin}: {record_name} {number} isn't valid UTF-8, {handling}"
+                );
+            };
+            match text {
+                Text::Invalid => {
+                    warn("leaving it unchanged");
+                    each(number, None, &read)?;
+                }
+                Text::Replaced(replaced) => {
+                    warn("replacing the invalid bytes");
+                    each(number, Some(&replaced), &read)?;
+                }
+                Text::Valid(valid) => each(number, Some(valid), &read)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` iff the records can have quoted fields, that can contain the delimiter.
+    fn is_quoted(&self) -> bool {
+        self.fields.as_ref().is_some_and(|fields| fields.csv)
+    }
 
-        let maybe_value = args.next();
-        if maybe_value.as_ref().is_some_and(|val| val == "--help") {
+    /// Parses the inputs [`Args`] into a [`Cli`] by checking the validity of the [`Args`].
+    ///
+    /// The case can be given as an option (`--snake`, `-s`, `--case=snake`) anywhere on the command
+    /// line, or else as the first positional argument. Every other positional argument is a value.
+    pub fn parse<Args: Iterator<Item = String>>(args: Args) -> Result<Self, Status> {
+        let lexed = args::lex(args);
+        if lexed.iter().any(Arg::is_help) {
             return Err(Status::Help);
         }
 
-        if let Some(last) = args.next() {
-            if last == "--help" {
-                return Err(Status::Help);
+        let mut option_action = None;
+        let mut flags = Flags::default();
+        let mut positionals = vec![];
+        let mut remaining = lexed.into_iter().peekable();
+        while let Some(arg) = remaining.next() {
+            if flags.set(&arg, &mut remaining)? {
+                continue;
+            }
+            let new_action = match arg {
+                Arg::Long(name, value) => Self::parse_long(&name, value, &mut remaining)?,
+                Arg::Short(short) => Self::parse_short(short)?,
+                Arg::Value(value) => {
+                    positionals.push(value);
+                    continue;
+                }
+            };
+            if option_action.replace(new_action).is_some() {
+                return Err(Status::Usage("Too many cases".to_owned()));
             }
-            return Err(Status::Error("Too many arguments".to_owned()));
         }
 
-        let value = maybe_value.as_deref().map(str::to_owned);
-        Ok(Self { case, value })
+        let mut values = positionals.into_iter().peekable();
+        let mut action = match option_action {
+            Some(option) => option,
+            None => Self::parse_action(&mut values)?,
+        };
+        action.configure(&flags)?;
+        let paths: Vec<_> = values.collect();
+        if matches!(action, Action::Rename { .. }) && paths.is_empty() {
+            return Err(Status::Usage("Missing paths for `rename`".to_owned()));
+        }
+        if matches!(action, Action::Lint(_)) && paths.is_empty() {
+            return Err(Status::Usage("Missing paths for `lint`".to_owned()));
+        }
+        if let Action::Code(code) = &action
+            && code.language.is_none()
+            && paths.is_empty()
+        {
+            return Err(Status::Usage(
+                "`code` needs `--lang` to read stdin".to_owned(),
+            ));
+        }
+        if matches!(
+            action,
+            Action::Code(_)
+                | Action::Env(_)
+                | Action::Ini(_)
+                | Action::Json(_)
+                | Action::Toml(_)
+                | Action::Yaml(_)
+        ) && !paths.is_empty()
+        {
+            flags.source = flags.source.with_files();
+        }
+        if flags.source != Source::Args {
+            if paths.is_empty() {
+                return Err(Status::Usage("Missing file paths".to_owned()));
+            }
+            if flags.join {
+                return Err(Status::Usage(
+                    "`--join` can't be used with files".to_owned(),
+                ));
+            }
+        }
+        let fields = flags.fields();
+        Ok(Self {
+            action,
+            delimiter: flags.delimiter,
+            fields,
+            invalid: flags.invalid,
+            jobs: flags.jobs,
+            join: flags.join,
+            pattern: flags.pattern,
+            source: flags.source,
+            values: paths,
+        })
     }
 
-    /// Parses the first argument to check if it is a valid case, an option or erroneous.
-    fn parse_case(first: Option<String>) -> Result<Case, Status> {
-        Err(match first {
-            None => Status::Error("Missing `case` argument".to_owned()),
-            Some(arg) if arg == "--help" => Status::Help,
-            Some(arg) => match Case::maybe_from(&arg) {
-                None => Status::Error(format!("{arg} isn't a valid case")),
-                Some(case) => return Ok(case),
-            },
+    /// Parses the first positional argument to check if it is a valid case, the `check`, `code`,
+    /// `convention`, `cycle`, `detect`, `env`, `ini`, `json`, `lint`, `rename`, `toml` or `yaml`
+    /// command or erroneous.
+    ///
+    /// The commands of [`CASE_COMMANDS`] take the case as the following positional argument.
+    fn parse_action<Values: Iterator<Item = String>>(
+        values: &mut Values,
+    ) -> Result<Action, Status> {
+        match values.next() {
+            None => Err(Status::Usage("Missing `case` argument".to_owned())),
+            Some(command) if CASE_COMMANDS.contains(&command.as_str()) => {
+                let case = values.next().ok_or_else(|| {
+                    Status::Usage(format!("Missing `case` argument for `{command}`"))
+                })?;
+                Ok(Action::with_case(&command, Self::parse_case(&case)?))
+            }
+            Some(arg) if arg == "convention" => Self::parse_convention(values),
+            Some(arg) if arg == "cycle" => Ok(Action::Cycle(CaseCycle::default())),
+            Some(arg) if arg == "detect" => Ok(Action::Detect { porcelain: false }),
+            Some(arg) if arg == "lint" => {
+                let language = values.next().ok_or_else(|| {
+                    Status::Usage("Missing `language` argument for `lint`".to_owned())
+                })?;
+                let parsed = language.parse().map_err(|_invalid: String| {
+                    Status::Usage(format!(
+                        "Invalid value `{language}` for `lint`, expected `c`, `go`, `js`, `py`, `rust` or `ts`"
+                    ))
+                })?;
+                Ok(Action::Lint(Lint::new(parsed)))
+            }
+            Some(arg) => Self::parse_case(&arg).map(Action::Convert),
+        }
+    }
+
+    /// Parses a case name, in any case or as one of its aliases.
+    fn parse_case(name: &str) -> Result<Case, Status> {
+        name.parse().map_err(|err: ParseCaseError| {
+            Status::InvalidCase(err.to_string(), suggest::closest_cases(name))
         })
     }
 
+    /// Parses the language and the kind of identifiers following the `convention` command.
+    fn parse_convention<Values: Iterator<Item = String>>(
+        values: &mut Values,
+    ) -> Result<Action, Status> {
+        let convention = values
+            .next()
+            .ok_or_else(|| {
+                Status::Usage("Missing `language` argument for `convention`".to_owned())
+            })?
+            .parse()
+            .map_err(|err: ParseConventionError| {
+                Status::Usage(format!("{err}, expected {CONVENTIONS}"))
+            })?;
+        let kind = values
+            .next()
+            .ok_or_else(|| Status::Usage("Missing `kind` argument for `convention`".to_owned()))?
+            .parse()
+            .map_err(|err: ParseIdentKindError| {
+                Status::Usage(format!("{err}, expected {KINDS}"))
+            })?;
+        Ok(Action::Convention { convention, kind })
+    }
+
+    /// Parses a long option, either `--case=<case>`, `--case <case>` or `--<case>`.
+    ///
+    /// Unknown options suggest the closest cases, like mistyped case names.
+    fn parse_long<Lexed: Iterator<Item = Arg>>(
+        name: &str,
+        value: Option<String>,
+        lexed: &mut Peekable<Lexed>,
+    ) -> Result<Action, Status> {
+        if name == "case" {
+            let case = option_value(name, value, lexed)?;
+            return Self::parse_case(&case).map(Action::Convert);
+        }
+        match (name.parse(), value) {
+            (Ok(case), None) => Ok(Action::Convert(case)),
+            (Ok(_), Some(_)) => Err(Status::Usage(format!("`--{name}` doesn't take a value"))),
+            (Err(_), _) => Err(Status::InvalidCase(
+                format!("Unknown option `--{name}`"),
+                suggest::closest_cases(name),
+            )),
+        }
+    }
+
+    /// Parses a short option, like `-s` for `snake_case`.
+    ///
+    /// Unknown options suggest the closest cases, like mistyped case names.
+    fn parse_short(short: char) -> Result<Action, Status> {
+        SHORT_OPTIONS
+            .iter()
+            .find(|(option, _)| *option == short)
+            .map(|(_, case)| Action::Convert(*case))
+            .ok_or_else(|| {
+                Status::InvalidCase(
+                    format!("Unknown option `-{short}`"),
+                    suggest::closest_cases(&short.to_string()),
+                )
+            })
+    }
+
     /// Runs the command, processing input and applying the selected case.
     ///
-    /// This function reads from stdin if no value is provided, or uses the provided value.
-    #[expect(clippy::print_stdout, reason = "this is a CLI")]
-    pub fn run(&self) -> io::Result<()> {
-        if let Some(value) = &self.value {
-            println!("{}", self.case.caseify(value));
+    /// The output is written to a locked and buffered stdout, and the command stops quietly if
+    /// stdout is closed early, like with `caseify Snake < big.txt | head`. The reports of `check`,
+    /// `lint` and `rename` are dropped instead, and the command still runs to the end, so that
+    /// it renames everything and fails if a check does.
+    pub fn run(&self) -> Result<(), Status> {
+        let mut stdout = BufWriter::new(io::stdout().lock());
+        if matches!(
+            self.action,
+            Action::Check(_) | Action::Lint(_) | Action::Rename { .. }
+        ) {
+            let mut report = Report::new(&mut stdout);
+            self.run_to(&mut report)?;
+            return Ok(report.flush()?);
+        }
+        match self.run_to(&mut stdout).and_then(|()| Ok(stdout.flush()?)) {
+            Err(Status::Io(err)) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Checks that the values are in the given case, and writes the ones that aren't with where
+    /// they come from, their record number and their fix.
+    ///
+    /// Fails with [`Status::CheckFailed`] if any value isn't in the given case.
+    fn run_check<W: Write>(&self, case: Case, out: &mut W) -> Result<(), Status> {
+        let mut violations: usize = 0;
+        let mut check = |origin: &str, line: usize, value: &str| {
+            if case.matches(value) {
+                return Ok(());
+            }
+            violations = violations.saturating_add(1);
+            writeln!(out, "{origin}:{line}: {value} -> {}", case.caseify(value))
+        };
+        if self.source != Source::Args {
+            for path in &self.values {
+                self.for_each_record(path, read_file(path)?.as_slice(), |line, text, _| {
+                    text.map_or(Ok(()), |value| check(path, line, value))
+                })
+                .map_err(|err| with_path(path, &err))?;
+            }
+        } else if self.join && !self.values.is_empty() {
+            check("<args>", 1, &self.values.join(" "))?;
+        } else if !self.values.is_empty() {
+            for (line, value) in (1..).zip(&self.values) {
+                check("<args>", line, value)?;
+            }
         } else {
-            let stdin = io::stdin();
-            for line in stdin.lock().lines() {
-                println!("{}", self.case.caseify(&line?));
+            self.for_each_record("<stdin>", io::stdin().lock(), |line, text, _| {
+                text.map_or(Ok(()), |value| check("<stdin>", line, value))
+            })?;
+        }
+        match violations {
+            0 => Ok(()),
+            1 => Err(Status::CheckFailed(format!("1 value isn't in {case} case"))),
+            _ => Err(Status::CheckFailed(format!(
+                "{violations} values aren't in {case} case"
+            ))),
+        }
+    }
+
+    /// Converts the records of a file, and writes them to the output or back to the file.
+    ///
+    /// The file is streamed to the output, but is read whole when it is converted in place.
+    fn run_file<W: Write>(&self, path: &str, out: &mut W) -> io::Result<()> {
+        let Source::InPlace(suffix) = &self.source else {
+            let file = File::open(path).map_err(|err| with_path(path, &err))?;
+            return self
+                .convert_records(path, file, out)
+                .map_err(|err| with_path(path, &err));
+        };
+        let mut converted = vec![];
+        self.convert_records(path, read_file(path)?.as_slice(), &mut converted)
+            .map_err(|err| with_path(path, &err))?;
+        files::replace(Path::new(path), &converted, suffix.as_deref())
+            .map_err(|err| with_path(path, &err))
+    }
+
+    /// Runs the command like [`Cli::run`], writing the output to the given writer.
+    ///
+    /// This function reads the records of stdin if no value is provided, or converts the provided
+    /// values, one per record, or all together if they are joined. With files, their records are
+    /// converted instead and either written or written back to the files. The terminators of the
+    /// records are kept as they are, except that the output of each of several files ends with
+    /// one, so that its last record isn't joined to the first one of the next file.
+    fn run_to<W: Write>(&self, out: &mut W) -> Result<(), Status> {
+        match &self.action {
+            Action::Check(case) => return self.run_check(*case, out),
+            Action::Lint(lint) => return lint.run(&self.values, out),
+            Action::Rename {
+                case,
+                dry_run,
+                recursive,
+            } => return rename::run(&self.values, *case, *recursive, *dry_run, out),
+            Action::Code(_)
+            | Action::Convention { .. }
+            | Action::Convert(_)
+            | Action::Cycle(_)
+            | Action::Detect { .. }
+            | Action::Env(_)
+            | Action::Ini(_)
+            | Action::Json(_)
+            | Action::Toml(_)
+            | Action::Yaml(_) => (),
+        }
+        let terminator = self.delimiter.output();
+        if self.source == Source::Files && self.values.len() > 1 {
+            let end = self.delimiter.end()?;
+            let mut ending = Ending::new(end, out);
+            for path in &self.values {
+                self.run_file(path, &mut ending)?;
+                if ending.is_unterminated() {
+                    write!(ending, "{terminator}")?;
+                }
+            }
+        } else if self.source != Source::Args {
+            for path in &self.values {
+                self.run_file(path, out)?;
             }
+        } else if self.join && !self.values.is_empty() {
+            write!(
+                out,
+                "{}{terminator}",
+                self.convert(1, &self.values.join(" "))
+            )?;
+        } else if !self.values.is_empty() {
+            for (number, value) in (1..).zip(&self.values) {
+                write!(out, "{}{terminator}", self.convert(number, value))?;
+            }
+        } else {
+            self.convert_records("<stdin>", io::stdin(), out)?;
         }
         Ok(())
     }
+
+    /// Writes the conversion of the text of the record with the given number, or the record
+    /// unchanged if it has no text, followed by its terminator.
+    fn write_record<W: Write>(
+        &self,
+        writer: &mut W,
+        number: usize,
+        text: Option<&str>,
+        record: &Record<'_>,
+    ) -> io::Result<()> {
+        match text {
+            Some(value) => writer.write_all(self.convert(number, value).as_bytes())?,
+            None => writer.write_all(&record.value)?,
+        }
+        writer.write_all(record.terminator.as_bytes())
+    }
+}
+
+/// Writer of reports that drops what is written to it once its reader is gone, like `head`.
+struct Report<W> {
+    /// Whether the reader is gone.
+    closed: bool,
+    /// Writer to write to while the reader is there.
+    writer: W,
+}
+
+impl<W: Write> Report<W> {
+    /// Writes to the writer until its reader is gone.
+    const fn new(writer: W) -> Self {
+        Self {
+            closed: false,
+            writer,
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided methods are based on `write` and `flush`"
+)]
+impl<W: Write> Write for Report<W> {
+    fn flush(&mut self) -> io::Result<()> {
+        if self.closed {
+            return Ok(());
+        }
+        match self.writer.flush() {
+            Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {
+                self.closed = true;
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.closed {
+            return Ok(buf.len());
+        }
+        match self.writer.write(buf) {
+            Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {
+                self.closed = true;
+                Ok(buf.len())
+            }
+            result => result,
+        }
+    }
+}
+
+/// Describes the cases the value is already in, as `value: Case` or as `Case\tvalue` for
+/// machine-readable output.
+fn detect(value: &str, porcelain: bool) -> String {
+    let names = Case::detect(value)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    if porcelain {
+        return format!("{}\t{value}", names.join(","));
+    }
+    match names.as_slice() {
+        [] => format!("{value}: no known case"),
+        [name] => format!("{value}: {name}"),
+        _ => format!(
+            "{value}: {} (ambiguous, a single word has no separator to tell these cases apart)",
+            names.join(", ")
+        ),
+    }
+}
+
+/// Returns the value of an option, given as `--name=value` or as the following argument.
+fn option_value<Lexed: Iterator<Item = Arg>>(
+    name: &str,
+    value: Option<String>,
+    lexed: &mut Peekable<Lexed>,
+) -> Result<String, Status> {
+    value
+        .or_else(|| {
+            lexed
+                .next_if(|arg| matches!(arg, Arg::Value(_)))
+                .and_then(Arg::into_value)
+        })
+        .ok_or_else(|| Status::Usage(format!("Missing value for `--{name}`")))
+}
+
+/// Parses the value of an option, given like with [`option_value`], with the error message of
+/// its type.
+fn parse_value<Lexed: Iterator<Item = Arg>, Value: FromStr<Err = String>>(
+    name: &str,
+    value: Option<String>,
+    lexed: &mut Peekable<Lexed>,
+) -> Result<Value, Status> {
+    option_value(name, value, lexed)?
+        .parse()
+        .map_err(Status::Usage)
+}
+
+/// Converts the keys of the `.env`, INI or properties file of the reader, and warns about the
+/// keys that are converted to the same key.
+fn recase_keys<R: Read, W: Write>(
+    origin: &str,
+    dialect: Dialect,
+    case: Case,
+    reader: R,
+    out: &mut W,
+) -> io::Result<()> {
+    warn_collisions(origin, ini::recase(dialect, case, reader, out)?);
+    Ok(())
+}
+
+/// Warns about the keys of the input that are converted to the same key.
+#[expect(clippy::print_stderr, reason = "this is a CLI")]
+fn warn_collisions(origin: &str, collisions: Vec<String>) {
+    for collision in collisions {
+        eprintln!("Warning: {origin}: {collision}");
+    }
+}
+
+/// Reads a file, with the path in the error message.
+fn read_file(path: &str) -> io::Result<Vec<u8>> {
+    fs::read(path).map_err(|err| with_path(path, &err))
+}
+
+/// Adds the path of the file to an IO error message.
+fn with_path(path: &str, err: &io::Error) -> io::Error {
+    io::Error::new(err.kind(), format!("{path}: {err}"))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Cli;
+    use std::io::{self, Write};
+
+    use super::{Cli, Delimiter, Report, Source};
+
+    /// Writer whose reader is gone.
+    struct Closed;
+
+    #[expect(
+        clippy::missing_trait_methods,
+        reason = "the provided methods are based on `write` and `flush`"
+    )]
+    impl Write for Closed {
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
 
     #[expect(clippy::unwrap_used, reason = "tests")]
     fn test(args: &[&str], input: &str, output: &str) {
         let owned_args = args.iter().map(|arg| (*arg).to_owned());
         let cli = Cli::parse(owned_args.into_iter()).unwrap();
-        assert_eq!(cli.case.caseify(input), output);
+        assert_eq!(cli.action.apply(input), output);
     }
 
     #[test]
@@ -137,4 +1223,148 @@ mod tests {
             "this.is.capitalised.case",
         );
     }
+    #[test]
+    fn cycle() {
+        test(&["cycle"], "foo_bar", "fooBar");
+        test(&["cycle"], "fooBar", "FooBar");
+        test(&["cycle"], "foo", "Foo");
+    }
+    #[test]
+    fn check() {
+        test(&["check", "Snake"], "fooBar", "foo_bar");
+        test(&["check", "Kebab"], "fooBar", "foo-bar");
+        test(&["--kebab", "check"], "check", "check");
+        test_values(&["-k", "check", "a"], &["check", "a"], false);
+        test_values(&["check", "dot", "a", "b"], &["a", "b"], false);
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "tests")]
+    fn reports() {
+        let mut written = vec![];
+        writeln!(Report::new(&mut written), "a -> A").unwrap();
+        assert_eq!(written, b"a -> A\n");
+        let mut closed = Report::new(Closed);
+        writeln!(closed, "a -> A").unwrap();
+        writeln!(closed, "b -> B").unwrap();
+        closed.flush().unwrap();
+        assert!(closed.closed);
+    }
+
+    #[test]
+    fn rename() {
+        test(&["rename", "Snake", "a"], "fooBar", "foo_bar");
+        test_values(&["-rn", "rename", "kebab", "a", "b"], &["a", "b"], false);
+    }
+
+    #[test]
+    fn detect() {
+        test(&["detect"], "foo_bar", "foo_bar: Snake");
+        test(&["detect"], "Hello World!", "Hello World!: no known case");
+        test(
+            &["detect"],
+            "Foo",
+            "Foo: Capitalised, Pascal, Sentence (ambiguous, a single word has no separator to tell these cases apart)",
+        );
+        test(&["detect", "--porcelain"], "fooBar", "Camel\tfooBar");
+        test(
+            &["--porcelain", "detect"],
+            "foo",
+            "Camel,Dot,Kebab,Snake\tfoo",
+        );
+        test(&["detect", "--porcelain"], "Hello World!", "\tHello World!");
+    }
+    #[test]
+    fn aliases() {
+        test(&["snake_case"], "ThisIsCamelCase", "this_is_camel_case");
+        test(
+            &["SCREAMING_SNAKE"],
+            "ThisIsCamelCase",
+            "THIS_IS_CAMEL_CASE",
+        );
+    }
+    #[test]
+    fn options() {
+        test(&["--snake"], "ThisIsCamelCase", "this_is_camel_case");
+        test(&["-s"], "ThisIsCamelCase", "this_is_camel_case");
+        test(&["--SNAKE-CASE"], "ThisIsCamelCase", "this_is_camel_case");
+        test(
+            &["--screaming-snake"],
+            "ThisIsCamelCase",
+            "THIS_IS_CAMEL_CASE",
+        );
+        test(&["--case=kebab"], "ThisIsCamelCase", "this-is-camel-case");
+        test(
+            &["--case", "css-var"],
+            "ThisIsCamelCase",
+            "--this-is-camel-case",
+        );
+    }
+
+    #[expect(clippy::unwrap_used, reason = "tests")]
+    fn test_values(args: &[&str], values: &[&str], join: bool) {
+        let owned_args = args.iter().map(|arg| (*arg).to_owned());
+        let cli = Cli::parse(owned_args.into_iter()).unwrap();
+        assert_eq!(cli.values, values);
+        assert_eq!(cli.join, join);
+    }
+
+    #[test]
+    fn argument_order() {
+        test_values(&["value", "--pascal"], &["value"], false);
+        test_values(&["--dot", "--", "-value"], &["-value"], false);
+        test_values(&["--", "Dot", "--help"], &["--help"], false);
+        test_values(&["--match", "value", "--snake"], &["value"], false);
+        test_values(&["--match", "get*", "--snake", "value"], &["value"], false);
+    }
+
+    #[test]
+    fn sources() {
+        let parse = |args: &[&str]| Cli::parse(args.iter().map(|arg| (*arg).to_owned())).ok();
+        assert_eq!(
+            parse(&["Snake", "-f", "a.txt"]).map(|cli| cli.source),
+            Some(Source::Files)
+        );
+        assert_eq!(
+            parse(&["Snake", "--in-place=.bak", "-f", "a.txt"]).map(|cli| cli.source),
+            Some(Source::InPlace(Some(".bak".to_owned())))
+        );
+        assert_eq!(
+            parse(&["Snake", "-i", "a.txt"]).map(|cli| cli.source),
+            Some(Source::InPlace(None))
+        );
+    }
+
+    #[test]
+    fn delimiters() {
+        let parse = |args: &[&str]| Cli::parse(args.iter().map(|arg| (*arg).to_owned())).ok();
+        assert_eq!(
+            parse(&["Snake"]).map(|cli| cli.delimiter),
+            Some(Delimiter::Lines)
+        );
+        assert_eq!(
+            parse(&["Snake", "-0"]).map(|cli| cli.delimiter),
+            Some(Delimiter::Custom("\0".to_owned()))
+        );
+        assert_eq!(
+            parse(&["--delimiter", ";", "Snake"]).map(|cli| cli.delimiter),
+            Some(Delimiter::Custom(";".to_owned()))
+        );
+        assert_eq!(
+            parse(&["--delimiter=, ", "Snake"]).map(|cli| cli.delimiter),
+            Some(Delimiter::Custom(", ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn multiple_values() {
+        test_values(&["Snake", "hello", "world"], &["hello", "world"], false);
+        test_values(
+            &["--join", "Snake", "hello", "world"],
+            &["hello", "world"],
+            true,
+        );
+        test_values(&["-sj", "hello"], &["hello"], true);
+        test_values(&["Snake"], &[], false);
+    }
 }
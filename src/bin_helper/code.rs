@@ -0,0 +1,1124 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Read, Write};
+
+use crate::bin_helper::lexer::{self, Language, Token, TokenKind};
+use crate::bin_helper::pattern::Pattern;
+use crate::parser::case::Case;
+use crate::parser::convention::{IdentKind, recase_keeping_affixes};
+
+/// Keywords of C that can start the type of a declaration.
+const C_TYPES: &[&str] = &[
+    "auto", "bool", "char", "const", "double", "enum", "extern", "float", "inline", "int", "long",
+    "register", "restrict", "short", "signed", "static", "struct", "union", "unsigned", "void",
+    "volatile",
+];
+
+/// Keywords of JavaScript and TypeScript that can precede the name of a class member.
+const MODIFIERS: &[&str] = &[
+    "abstract",
+    "async",
+    "declare",
+    "get",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "set",
+    "static",
+];
+
+/// Options to convert the identifiers of source files to a case.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Code {
+    /// Case to convert the identifiers to.
+    pub case: Case,
+    /// Kinds of the only identifiers that are converted, or all of them if empty.
+    pub kinds: Vec<IdentKind>,
+    /// Language of the sources, or `None` to tell it from the extension of each file.
+    pub language: Option<Language>,
+    /// Pattern of the only identifiers that are converted, if any.
+    pub pattern: Option<Pattern>,
+}
+
+impl Code {
+    /// Returns the identifier converted if it has to be, keeping its leading and trailing
+    /// underscores, or `None` to keep it unchanged.
+    fn convert(&self, language: Language, kind: IdentKind, name: &str) -> Option<String> {
+        if !(self.kinds.is_empty() || self.kinds.contains(&kind))
+            || self
+                .pattern
+                .as_ref()
+                .is_some_and(|pattern| !pattern.matches(name))
+        {
+            return None;
+        }
+        let recased = recase_keeping_affixes(self.case, name);
+        (recased != name && !language.is_keyword(&recased)).then_some(recased)
+    }
+
+    /// Returns the options to convert all the identifiers declared in the sources to the case.
+    pub const fn new(case: Case) -> Self {
+        Self {
+            case,
+            kinds: vec![],
+            language: None,
+            pattern: None,
+        }
+    }
+
+    /// Converts the identifiers of the source of the reader, and writes the source.
+    ///
+    /// Only the names declared in the source are converted, wherever they are used, so names
+    /// coming from other files and libraries are kept. Strings, comments, keywords and operators
+    /// are written unchanged. The language is taken from the extension of `origin` if it isn't
+    /// set, and it fails with [`io::ErrorKind::InvalidData`] if it can't be.
+    pub fn recase<R: Read, W: Write>(
+        &self,
+        origin: &str,
+        mut reader: R,
+        out: &mut W,
+    ) -> io::Result<()> {
+        let language = self
+            .language
+            .or_else(|| Language::from_path(origin))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "can't tell the language from the file extension, use `--lang`",
+                )
+            })?;
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let output = rewrite(language, &text, |kind, name| {
+            self.convert(language, kind, name)
+        });
+        out.write_all(output.as_bytes())
+    }
+}
+
+/// Name declared in a source, with where it is declared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Declaration<'text> {
+    /// Whether the name is used in the interpolations of strings, where it can't be renamed.
+    pub interpolated: bool,
+    /// Kind the name is declared with.
+    pub kind: IdentKind,
+    /// Name as written in the source.
+    pub name: &'text str,
+    /// Byte offset of the name in the source.
+    pub offset: usize,
+    /// Whether other files can't see the name, like locals and the items that aren't exported.
+    pub private: bool,
+}
+
+/// Finds where the names of a source are declared, and the kind of each of their occurrences.
+///
+/// The declarations are recognised from the tokens around the names, without parsing: this is
+/// enough for the usual code, and the names whose declaration is missed are left unchanged.
+struct Scanner<'text> {
+    /// Depth of the generic angle brackets of Rust and TypeScript the tokens are in.
+    angles: usize,
+    /// Whether the tokens are in the parameters of a Rust closure.
+    closure: bool,
+    /// Kinds each name is declared with, in order.
+    declared: HashMap<&'text str, Vec<IdentKind>>,
+    /// Indentation of the tokens that start a line.
+    indents: Vec<Option<usize>>,
+    /// Depth of the brackets of the Python lambda the tokens are the parameters of.
+    lambda: Option<usize>,
+    /// Language of the source.
+    language: Language,
+    /// Whether the next parenthesis opens the parameters of a function.
+    params_pending: bool,
+    /// Index of the bracket matching each bracket.
+    partners: Vec<Option<usize>>,
+    /// Scope of the next brace, opened by the keyword of a declaration.
+    pending_scope: Option<Scope>,
+    /// Whether other files can't see the name declared at each token.
+    private: Vec<bool>,
+    /// Indentations of the Python blocks the tokens are in, and whether they are classes.
+    python_blocks: Vec<(usize, bool)>,
+    /// Open brackets, with the scopes they start.
+    scopes: Vec<(char, Scope)>,
+    /// Kind of the name declared at each token, if any.
+    sites: Vec<Option<IdentKind>>,
+    /// Whether whitespace or a comment precedes each token.
+    spaced: Vec<bool>,
+    /// Tokens of the source, without whitespace and comments.
+    tokens: Vec<Token<'text>>,
+    /// Whether a C `typedef` is being declared.
+    typedef: bool,
+    /// Types the variables and fields are declared or initialised with, like `Server` for `s` in
+    /// `func (s *Server)`, when the type is a name.
+    types: HashMap<&'text str, Vec<&'text str>>,
+}
+
+impl<'text> Scanner<'text> {
+    /// Returns `true` iff the name at the index is declared by the C type before it, like in
+    /// `unsigned int count` or `struct node *next`.
+    fn c_declares(&self, index: usize) -> bool {
+        let mut type_index = index;
+        loop {
+            let Some(before) = type_index.checked_sub(1) else {
+                return false;
+            };
+            type_index = before;
+            if self.text(type_index) != "*" {
+                break;
+            }
+        }
+        let is_type = self.is_identifier(type_index) || C_TYPES.contains(&self.text(type_index));
+        let Some(before) = type_index.checked_sub(1) else {
+            return is_type;
+        };
+        let in_list = matches!(self.scopes.last(), Some((_, Scope::Params | Scope::Record)));
+        is_type
+            && (self.indents.get(type_index).is_some_and(Option::is_some)
+                || matches!(self.text(before), ";" | "{" | "}")
+                || (in_list && matches!(self.text(before), "(" | ","))
+                || (self.text(before) == "(" && self.text_before(before, 1) == "for")
+                || C_TYPES.contains(&self.text(before)))
+    }
+
+    /// Finds the declarations of the source.
+    fn declare(&mut self) {
+        for index in 0..self.tokens.len() {
+            self.track_line(index);
+            let site = if self.is_identifier(index) {
+                self.site(index)
+            } else {
+                None
+            };
+            if let Some(kind) = site {
+                if let Some(declared) = self.sites.get_mut(index) {
+                    *declared = Some(kind);
+                }
+                let is_private = self.is_private(index, kind);
+                if let Some(private) = self.private.get_mut(index) {
+                    *private = is_private;
+                }
+                let kinds = self.declared.entry(self.text(index)).or_default();
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+            let is_go_receiver = self.language == Language::Go
+                && self.is_identifier(index)
+                && self.previous(index) == "("
+                && self.text_before(index, 2) == "func";
+            if is_go_receiver || matches!(site, Some(IdentKind::Field | IdentKind::Local)) {
+                self.declare_type(index);
+            }
+            self.track(index);
+        }
+    }
+
+    /// Adds the type of the variable or field declared at the index, if it is a name.
+    ///
+    /// The type is the one before the name in C, or else the one after the name, after `:` or
+    /// after the `=` of the initial value, like `Server` in `s := &Server{}`.
+    fn declare_type(&mut self, index: usize) {
+        let type_index = if self.language == Language::C {
+            let mut before = index;
+            while let Some(previous) = before.checked_sub(1) {
+                before = previous;
+                if self.text(before) != "*" {
+                    break;
+                }
+            }
+            before
+        } else {
+            let next = index.saturating_add(1);
+            let mut after = match (self.text(next), self.text(next.saturating_add(1))) {
+                (":", "=") => next.saturating_add(2),
+                (":" | "=", after_next) if !matches!(after_next, ":" | "=") => {
+                    next.saturating_add(1)
+                }
+                _ => next,
+            };
+            while matches!(self.text(after), "&" | "*" | "[" | "]" | "mut" | "new") {
+                after = after.saturating_add(1);
+            }
+            after
+        };
+        if type_index != index && self.is_identifier(type_index) {
+            let type_name = self.text(type_index);
+            let types = self.types.entry(self.text(index)).or_default();
+            if !types.contains(&type_name) {
+                types.push(type_name);
+            }
+        }
+    }
+
+    /// Returns the kind of the name declared at the index by the keyword before it, if any.
+    fn declared_by_keyword(&mut self, index: usize) -> Option<IdentKind> {
+        let next = self.text(index.saturating_add(1));
+        match self.previous(index) {
+            "def" | "fn" | "func" | "function" => Some(self.function()),
+            "class" | "enum" | "interface" | "struct" | "trait" | "type" | "union" => {
+                Some(IdentKind::Type)
+            }
+            "const" if self.is_javascript() => Some(self.variable(index)),
+            "const" | "static" => Some(IdentKind::Constant),
+            "let" | "var" if !matches!(next, "(" | "{") && !self.is_path(index) => {
+                Some(self.variable(index))
+            }
+            "define" if self.language == Language::C => Some(IdentKind::Constant),
+            "for" if matches!(next, "in" | ",") => Some(IdentKind::Local),
+            "as" if self.language == Language::Python => Some(IdentKind::Local),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of the name declared at the index by the syntax of the language, if any.
+    fn declared_by_syntax(&mut self, index: usize) -> Option<IdentKind> {
+        let previous = self.previous(index);
+        let next = self.text(index.saturating_add(1));
+        let after_next = self.text(index.saturating_add(2));
+        let assigns = (next == "=" && after_next != "=") || next == ":";
+        match self.language {
+            Language::C if self.typedef && self.scopes.is_empty() && next == ";" => {
+                Some(IdentKind::Type)
+            }
+            Language::C if self.c_declares(index) => match next {
+                "(" if self.scopes.is_empty() => Some(self.function()),
+                "=" | ";" | "," | "[" => Some(self.variable(index)),
+                _ => None,
+            },
+            Language::Go if previous == ")" && self.is_receiver(index.saturating_sub(1)) => {
+                Some(self.function())
+            }
+            Language::Go if self.is_short_declaration(index) => Some(self.variable(index)),
+            Language::JavaScript | Language::TypeScript
+                if next == "=" && after_next == ">" && previous != "." =>
+            {
+                Some(IdentKind::Local)
+            }
+            Language::Python if previous == "." && assigns => {
+                (self.text_before(index, 2) == "self").then_some(IdentKind::Field)
+            }
+            Language::Python
+                if self.scopes.is_empty()
+                    && assigns
+                    && self.indents.get(index).is_some_and(Option::is_some) =>
+            {
+                let kind = self.variable(index);
+                Some(if kind == IdentKind::Local && self.is_in_python_class() {
+                    IdentKind::Field
+                } else {
+                    kind
+                })
+            }
+            Language::Python
+                if self.lambda == Some(self.scopes.len())
+                    && matches!(previous, "lambda" | "," | "*")
+                    && matches!(next, "," | ":" | "=") =>
+            {
+                Some(IdentKind::Local)
+            }
+            Language::Rust
+                if self.closure
+                    && matches!(previous, "|" | "," | "&")
+                    && matches!(next, "," | "|" | ":") =>
+            {
+                Some(IdentKind::Local)
+            }
+            Language::C
+            | Language::Go
+            | Language::JavaScript
+            | Language::Python
+            | Language::Rust
+            | Language::TypeScript => None,
+        }
+    }
+
+    /// Returns the kind of the name declared at the index by the scope it is in, if any.
+    fn declared_in_scope(&mut self, index: usize) -> Option<IdentKind> {
+        let &(bracket, scope) = self.scopes.last()?;
+        let previous = self.previous(index);
+        let next = self.text(index.saturating_add(1));
+        let after_next = self.text(index.saturating_add(2));
+        let starts_member = matches!(previous, "{" | "," | ";" | "}" | ")")
+            || MODIFIERS.contains(&previous)
+            || self.indents.get(index).is_some_and(Option::is_some);
+        let is_field =
+            (next == ":" && !matches!(after_next, ":" | "=")) || (next == "?" && after_next == ":");
+        match scope {
+            Scope::Class if starts_member && matches!(next, "(" | "<") => Some(self.function()),
+            Scope::Class if starts_member && matches!(next, "=" | ";" | ":" | "?" | "!") => {
+                Some(IdentKind::Field)
+            }
+            Scope::Enum if matches!(previous, "{" | ",") => Some(IdentKind::EnumVariant),
+            Scope::Params if self.is_parameter(index) => Some(IdentKind::Local),
+            Scope::Record
+                if self.language == Language::C
+                    && self.c_declares(index)
+                    && matches!(next, ";" | "," | "[" | ":") =>
+            {
+                Some(IdentKind::Field)
+            }
+            Scope::Record
+                if self.language == Language::Go
+                    && (self.indents.get(index).is_some_and(Option::is_some)
+                        || matches!(previous, "{" | ";"))
+                    && !matches!(next, "." | "(" | "}" | ";")
+                    && self
+                        .indents
+                        .get(index.saturating_add(1))
+                        .is_some_and(Option::is_none) =>
+            {
+                Some(IdentKind::Field)
+            }
+            Scope::Class | Scope::Other | Scope::Record
+                if bracket == '{'
+                    && self.language != Language::Python
+                    && starts_member
+                    && is_field =>
+            {
+                Some(IdentKind::Field)
+            }
+            Scope::Class | Scope::Enum | Scope::Other | Scope::Params | Scope::Record => None,
+        }
+    }
+
+    /// Declares a function, whose parameters are in the next parentheses.
+    const fn function(&mut self) -> IdentKind {
+        self.params_pending = true;
+        IdentKind::Function
+    }
+
+    /// Returns `true` iff the parenthesis at the index opens the parameters of an arrow function.
+    fn is_arrow(&self, index: usize) -> bool {
+        self.is_javascript()
+            && self
+                .partners
+                .get(index)
+                .copied()
+                .flatten()
+                .is_some_and(|close| {
+                    self.text(close.saturating_add(1)) == "="
+                        && self.text(close.saturating_add(2)) == ">"
+                })
+    }
+
+    /// Returns `true` iff the token at the index is an identifier.
+    fn is_identifier(&self, index: usize) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Identifier)
+    }
+
+    /// Returns `true` iff the tokens are in the body of a Python class, and not of its methods.
+    fn is_in_python_class(&self) -> bool {
+        self.python_blocks
+            .last()
+            .is_some_and(|&(_, is_class)| is_class)
+    }
+
+    /// Returns `true` iff the language is JavaScript or TypeScript.
+    const fn is_javascript(&self) -> bool {
+        matches!(self.language, Language::JavaScript | Language::TypeScript)
+    }
+
+    /// Returns `true` iff the token at the index is a receiver whose type is declared in the
+    /// source: `self`, `this`, a variable or field declared with such a type, or a call to its
+    /// constructor, like `Store()` or `new Store()`.
+    fn is_local_receiver(&self, index: usize) -> bool {
+        let is_local_type = |name: &str| {
+            name == "Self"
+                || self
+                    .declared
+                    .get(name)
+                    .is_some_and(|kinds| kinds.contains(&IdentKind::Type))
+        };
+        match self.text(index) {
+            "self" | "this" => true,
+            ")" => self
+                .partners
+                .get(index)
+                .copied()
+                .flatten()
+                .and_then(|open| open.checked_sub(1))
+                .is_some_and(|callee| {
+                    self.is_identifier(callee) && is_local_type(self.text(callee))
+                }),
+            name => {
+                self.is_identifier(index)
+                    && self.types.get(name).is_some_and(|types| {
+                        types.iter().any(|&type_name| is_local_type(type_name))
+                    })
+            }
+        }
+    }
+
+    /// Returns `true` iff the name at the index is a parameter, in the parameters of a function.
+    fn is_parameter(&self, index: usize) -> bool {
+        let previous = self.previous(index);
+        let next = self.text(index.saturating_add(1));
+        match self.language {
+            Language::C => self.c_declares(index) && matches!(next, "," | ")" | "["),
+            Language::Go => matches!(previous, "(" | ",") && !matches!(next, "." | ")"),
+            Language::JavaScript | Language::Python | Language::Rust | Language::TypeScript => {
+                matches!(previous, "(" | "," | "*" | ".")
+                    && matches!(next, ":" | "," | ")" | "=" | "?")
+                    && !self.is_path(index)
+            }
+        }
+    }
+
+    /// Returns `true` iff the name at the index starts a path, like `Ordering::Less`.
+    fn is_path(&self, index: usize) -> bool {
+        self.text(index.saturating_add(1)) == ":" && self.text(index.saturating_add(2)) == ":"
+    }
+
+    /// Returns `true` iff other files can't see the name declared at the index with the kind.
+    ///
+    /// These are the locals of functions and blocks, and the top-level names that aren't `pub`
+    /// in Rust, that are `static` in C or that aren't exported in JavaScript, with the `private`
+    /// members and the functions nested in other ones of JavaScript. The other top-level names of
+    /// Go and Python are seen by the other files of their package, or can be imported.
+    fn is_private(&self, index: usize, kind: IdentKind) -> bool {
+        if kind == IdentKind::Local
+            && (!self.scopes.is_empty()
+                || (self.language == Language::Python && !self.python_blocks.is_empty()))
+        {
+            return true;
+        }
+        let line_start = (0..=index)
+            .rev()
+            .find(|&start| self.indents.get(start).is_some_and(Option::is_some))
+            .unwrap_or_default();
+        let has = |modifier: &str| {
+            self.tokens
+                .get(line_start..index)
+                .unwrap_or_default()
+                .iter()
+                .any(|token| token.text == modifier)
+        };
+        match (self.language, self.scopes.last()) {
+            (Language::C, None) => has("static"),
+            (Language::JavaScript | Language::TypeScript, None) => !has("export"),
+            (Language::JavaScript | Language::TypeScript, Some((_, Scope::Class))) => {
+                has("private") || self.text(index).starts_with('#')
+            }
+            (
+                Language::JavaScript | Language::TypeScript,
+                Some((_, Scope::Other | Scope::Params)),
+            ) => kind != IdentKind::Field,
+            (Language::Rust, None) => !has("pub"),
+            (Language::C | Language::Rust, Some(_))
+            | (
+                Language::JavaScript | Language::TypeScript,
+                Some((_, Scope::Enum | Scope::Record)),
+            )
+            | (Language::Go | Language::Python, _) => false,
+        }
+    }
+
+    /// Returns `true` iff the parenthesis at the index closes the receiver of a Go method, like
+    /// in `func (s *Server) Run()`.
+    fn is_receiver(&self, index: usize) -> bool {
+        self.partners
+            .get(index)
+            .copied()
+            .flatten()
+            .is_some_and(|open| self.text_before(open, 1) == "func")
+    }
+
+    /// Returns `true` iff the name at the index starts a Go short variable declaration, like
+    /// `count, err := parse()`.
+    fn is_short_declaration(&self, index: usize) -> bool {
+        let mut end = index.saturating_add(1);
+        while self.text(end) == "," && self.is_identifier(end.saturating_add(1)) {
+            end = end.saturating_add(2);
+        }
+        self.previous(index) != "."
+            && self.text(end) == ":"
+            && self.text(end.saturating_add(1)) == "="
+    }
+
+    /// Returns a scanner of the tokens of the source, without their declarations yet.
+    fn new(language: Language, tokens: &[Token<'text>]) -> Self {
+        let mut significant = vec![];
+        let mut indents = vec![];
+        let mut spaced = vec![];
+        let mut indent = Some(0);
+        let mut is_spaced = false;
+        for token in tokens {
+            match token.kind {
+                TokenKind::Comment => is_spaced = true,
+                TokenKind::Whitespace => {
+                    is_spaced = true;
+                    if indent.is_some() || token.text.contains('\n') {
+                        indent = token.text.rsplit('\n').next().map(str::len);
+                    }
+                }
+                TokenKind::Identifier
+                | TokenKind::Keyword
+                | TokenKind::Number
+                | TokenKind::Punctuation
+                | TokenKind::String => {
+                    significant.push(*token);
+                    indents.push(indent.take());
+                    spaced.push(is_spaced);
+                    is_spaced = false;
+                }
+            }
+        }
+        let mut partners = vec![None; significant.len()];
+        let mut open = vec![];
+        for (index, token) in significant.iter().enumerate() {
+            match token.text {
+                "(" | "[" | "{" => open.push(index),
+                ")" | "]" | "}" => {
+                    if let Some(start) = open.pop() {
+                        if let Some(partner) = partners.get_mut(start) {
+                            *partner = Some(index);
+                        }
+                        if let Some(partner) = partners.get_mut(index) {
+                            *partner = Some(start);
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        Self {
+            angles: 0,
+            closure: false,
+            declared: HashMap::new(),
+            indents,
+            lambda: None,
+            language,
+            params_pending: false,
+            partners,
+            pending_scope: None,
+            private: vec![false; significant.len()],
+            python_blocks: vec![],
+            scopes: vec![],
+            sites: vec![None; significant.len()],
+            spaced,
+            tokens: significant,
+            typedef: false,
+            types: HashMap::new(),
+        }
+    }
+
+    /// Returns the kind of the identifier at the index, if it is a name declared in the source.
+    ///
+    /// The names after `.` or `->` are fields or methods, only when what they are accessed on is
+    /// known to be of a type declared in the source, so that `localStorage.getItem` is kept even
+    /// with a `getItem` function. The other names take the first kind they are declared with,
+    /// other than a field.
+    fn occurrence(&self, index: usize) -> Option<IdentKind> {
+        if !self.is_identifier(index) || self.previous(index) == "'" {
+            return None;
+        }
+        if let Some(kind) = self.sites.get(index).copied().flatten() {
+            return Some(kind);
+        }
+        let kinds = self.declared.get(self.text(index))?;
+        let previous = self.previous(index);
+        let receiver = if previous == "." && self.text_before(index, 2) != "." {
+            index.checked_sub(2)
+        } else if self.language == Language::C
+            && previous == ">"
+            && self.text_before(index, 2) == "-"
+        {
+            index.checked_sub(3)
+        } else {
+            None
+        };
+        if let Some(member_of) = receiver {
+            if !self.is_local_receiver(member_of) {
+                return None;
+            }
+            let is_call = self.text(index.saturating_add(1)) == "(";
+            return [
+                (is_call, IdentKind::Function),
+                (true, IdentKind::Field),
+                (true, IdentKind::Function),
+            ]
+            .into_iter()
+            .find(|&(applies, kind)| applies && kinds.contains(&kind))
+            .map(|(_, kind)| kind);
+        }
+        kinds
+            .iter()
+            .find(|&&kind| kind != IdentKind::Field)
+            .or_else(|| kinds.first())
+            .copied()
+    }
+
+    /// Returns the text of the token before the index, skipping the `mut` and `ref` of Rust.
+    fn previous(&self, index: usize) -> &'text str {
+        let mut before = index;
+        while let Some(previous) = before.checked_sub(1) {
+            before = previous;
+            let text = self.text(before);
+            if !(self.language == Language::Rust && matches!(text, "mut" | "ref")) {
+                return text;
+            }
+        }
+        ""
+    }
+
+    /// Returns the kind of the name declared at the index, if it is a declaration.
+    ///
+    /// Nothing is declared in generic angle brackets, whose parameters are left unchanged.
+    fn site(&mut self, index: usize) -> Option<IdentKind> {
+        if self.angles > 0 {
+            return None;
+        }
+        self.declared_by_keyword(index)
+            .or_else(|| self.declared_in_scope(index))
+            .or_else(|| self.declared_by_syntax(index))
+    }
+
+    /// Returns the text of the token at the index, or an empty text past the end.
+    fn text(&self, index: usize) -> &'text str {
+        self.tokens.get(index).map_or("", |token| token.text)
+    }
+
+    /// Returns the text of the token the given number of tokens before the index.
+    fn text_before(&self, index: usize, count: usize) -> &'text str {
+        index
+            .checked_sub(count)
+            .map_or("", |before| self.text(before))
+    }
+
+    /// Updates the brackets and the pending declarations after the token at the index.
+    fn track(&mut self, index: usize) {
+        let text = self.text(index);
+        match text {
+            "(" | "[" | "{" => {
+                let scope = match text {
+                    "{" => self.pending_scope.take().unwrap_or(Scope::Other),
+                    "(" if self.params_pending || self.is_arrow(index) => Scope::Params,
+                    _ => Scope::Other,
+                };
+                if text != "[" {
+                    self.params_pending = false;
+                    self.pending_scope = None;
+                }
+                self.scopes
+                    .push((text.chars().next().unwrap_or_default(), scope));
+            }
+            ")" | "]" | "}" => {
+                self.scopes.pop();
+            }
+            ";" | "=" => {
+                self.params_pending = false;
+                self.pending_scope = None;
+                self.typedef = self.typedef && !(text == ";" && self.scopes.is_empty());
+            }
+            ":" if self.lambda == Some(self.scopes.len()) => self.lambda = None,
+            "<" if matches!(self.language, Language::Rust | Language::TypeScript)
+                && self.spaced.get(index) == Some(&false)
+                && index.checked_sub(1).is_some_and(|before| {
+                    self.tokens.get(before).is_some_and(|token| {
+                        matches!(token.kind, TokenKind::Identifier | TokenKind::Keyword)
+                    })
+                }) =>
+            {
+                self.angles = self.angles.saturating_add(1);
+            }
+            ">" if !matches!(self.text_before(index, 1), "-" | "=") => {
+                self.angles = self.angles.saturating_sub(1);
+            }
+            "|" if self.language == Language::Rust => {
+                self.closure = !self.closure
+                    && matches!(self.previous(index), "(" | "," | "=" | "move" | "{" | ";");
+            }
+            "class" | "interface" if self.is_javascript() => {
+                self.pending_scope = Some(Scope::Class);
+            }
+            "enum" => self.pending_scope = Some(Scope::Enum),
+            "struct" | "union" => self.pending_scope = Some(Scope::Record),
+            "func" | "function" => self.params_pending = true,
+            "lambda" if self.language == Language::Python => {
+                self.lambda = Some(self.scopes.len());
+            }
+            "typedef" => self.typedef = true,
+            _ => (),
+        }
+    }
+
+    /// Updates the Python blocks the token at the index is in, if it starts a line.
+    fn track_line(&mut self, index: usize) {
+        let Some(indent) = self.indents.get(index).copied().flatten() else {
+            return;
+        };
+        if self.language != Language::Python || !self.scopes.is_empty() {
+            return;
+        }
+        while self
+            .python_blocks
+            .last()
+            .is_some_and(|&(block, _)| block >= indent)
+        {
+            self.python_blocks.pop();
+        }
+        let text = self.text(index);
+        if matches!(text, "async" | "class" | "def") {
+            self.python_blocks.push((indent, text == "class"));
+        }
+    }
+
+    /// Returns the kind of the variable declared at the index: a function if it is assigned one
+    /// in JavaScript, or a constant if its name is in capitals.
+    fn variable(&self, index: usize) -> IdentKind {
+        let name = self.text(index);
+        let value = index.saturating_add(2);
+        let is_function = self.text(index.saturating_add(1)) == "="
+            && (matches!(self.text(value), "async" | "function")
+                || (self.text(value) == "(" && self.is_arrow(value))
+                || (self.is_identifier(value)
+                    && self.text(value.saturating_add(1)) == "="
+                    && self.text(value.saturating_add(2)) == ">"));
+        if self.is_javascript() && is_function {
+            IdentKind::Function
+        } else if name.chars().any(char::is_alphabetic) && !name.chars().any(char::is_lowercase) {
+            IdentKind::Constant
+        } else {
+            IdentKind::Local
+        }
+    }
+}
+
+/// Scope started by a bracket, that tells what the names directly in it declare.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scope {
+    /// Body of a JavaScript or TypeScript class or interface, declaring fields and methods.
+    Class,
+    /// Body of an enum, declaring its variants.
+    Enum,
+    /// Any other bracket.
+    Other,
+    /// Parameters of a function, declaring them.
+    Params,
+    /// Body of a struct or union, declaring its fields in C and Go.
+    Record,
+}
+
+/// Returns the first declaration of each name of the source, for each kind it is declared with,
+/// in the order of the source.
+///
+/// A name is private if all its declarations with the kind are.
+pub fn declarations(language: Language, text: &str) -> Vec<Declaration<'_>> {
+    let tokens = lexer::lex(language, text);
+    let mut scanner = Scanner::new(language, &tokens);
+    scanner.declare();
+    let interpolated = interpolated(language, &tokens);
+    let mut found: Vec<Declaration<'_>> = vec![];
+    let mut seen: HashMap<(&str, IdentKind), usize> = HashMap::new();
+    let mut offset: usize = 0;
+    let mut index: usize = 0;
+    for token in &tokens {
+        if !matches!(token.kind, TokenKind::Comment | TokenKind::Whitespace) {
+            if let Some(kind) = scanner.sites.get(index).copied().flatten() {
+                let private = scanner.private.get(index).copied().unwrap_or_default();
+                match seen.entry((token.text, kind)) {
+                    Entry::Occupied(first) => {
+                        if let Some(declaration) = found.get_mut(*first.get()) {
+                            declaration.private = declaration.private && private;
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(found.len());
+                        found.push(Declaration {
+                            interpolated: interpolated.contains(token.text),
+                            kind,
+                            name: token.text,
+                            offset,
+                            private,
+                        });
+                    }
+                }
+            }
+            index = index.saturating_add(1);
+        }
+        offset = offset.saturating_add(token.text.len());
+    }
+    found
+}
+
+/// Returns the names used in the interpolations of the strings of the source, like `name` and
+/// `width` in `"{name:>width$}"` in Rust or `f"{name}"` in Python.
+///
+/// The interpolations of the templates of JavaScript are tokens of their own, and so are left
+/// out. The format strings of Rust aren't told apart from the other strings, and every name in the
+/// interpolations of Python is taken, so some names may be kept for nothing, but none is missed.
+fn interpolated<'text>(language: Language, tokens: &[Token<'text>]) -> HashSet<&'text str> {
+    let mut names = HashSet::new();
+    for token in tokens {
+        let Some(quote) = token.text.find(['"', '\'']) else {
+            continue;
+        };
+        let prefix = token.text.get(..quote).unwrap_or_default();
+        let interpolates = match language {
+            Language::Python => prefix.contains(['f', 'F']),
+            Language::Rust => token
+                .text
+                .get(quote..)
+                .is_some_and(|rest| rest.starts_with('"')),
+            Language::C | Language::Go | Language::JavaScript | Language::TypeScript => false,
+        };
+        if !interpolates {
+            continue;
+        }
+        let mut rest = token.text.get(quote..).unwrap_or_default();
+        while let Some(open) = rest.find('{') {
+            let after = rest.get(open.saturating_add(1)..).unwrap_or_default();
+            if let Some(escaped) = after.strip_prefix('{') {
+                rest = escaped;
+                continue;
+            }
+            let mut depth: usize = 0;
+            let end = after
+                .find(|ch| {
+                    match ch {
+                        '{' => depth = depth.saturating_add(1),
+                        '}' if depth == 0 => return true,
+                        '}' => depth = depth.saturating_sub(1),
+                        _ => (),
+                    }
+                    false
+                })
+                .unwrap_or(after.len());
+            let hole = after.get(..end).unwrap_or_default();
+            if language == Language::Rust {
+                let (argument, spec) = hole.split_once(':').unwrap_or((hole, ""));
+                let counts = spec.split('$').rev().skip(1).filter_map(|before| {
+                    before
+                        .rfind(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                        .map_or(Some(before), |last| before.get(last.saturating_add(1)..))
+                });
+                names.extend(counts.chain([argument.trim()]).filter(|name| is_word(name)));
+            } else {
+                names.extend(
+                    hole.split(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                        .filter(|name| is_word(name)),
+                );
+            }
+            rest = after.get(end..).unwrap_or_default();
+        }
+    }
+    names
+}
+
+/// Returns `true` iff the text is a single name, like the ones used in interpolations.
+fn is_word(text: &str) -> bool {
+    text.chars()
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && text.chars().all(|ch| ch.is_alphanumeric() || ch == '_')
+}
+
+/// Rewrites the source, replacing the occurrences of the names declared in it with what `rename`
+/// returns for their kind and name, if anything.
+///
+/// Strings, comments, keywords, operators and the names that aren't declared in the source are
+/// kept unchanged, and so are the names used in the interpolations of strings, like `{name}` in
+/// the format strings of Rust, so that they still refer to the same thing.
+pub fn rewrite<Rename: Fn(IdentKind, &str) -> Option<String>>(
+    language: Language,
+    text: &str,
+    rename: Rename,
+) -> String {
+    let tokens = lexer::lex(language, text);
+    let mut scanner = Scanner::new(language, &tokens);
+    scanner.declare();
+    let interpolated = interpolated(language, &tokens);
+    let mut output = String::with_capacity(text.len());
+    let mut index: usize = 0;
+    for token in &tokens {
+        if matches!(token.kind, TokenKind::Comment | TokenKind::Whitespace) {
+            output.push_str(token.text);
+            continue;
+        }
+        let renamed = scanner
+            .occurrence(index)
+            .filter(|_| !interpolated.contains(token.text))
+            .and_then(|kind| rename(kind, token.text));
+        output.push_str(renamed.as_deref().unwrap_or(token.text));
+        index = index.saturating_add(1);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "tests")]
+
+    use super::{Code, declarations};
+    use crate::bin_helper::lexer::Language;
+    use crate::bin_helper::pattern::Pattern;
+    use crate::parser::case::Case;
+    use crate::parser::convention::IdentKind;
+
+    fn test(code: &Code, origin: &str, input: &str, expected: &str) {
+        let mut out = vec![];
+        code.recase(origin, input.as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    fn with_kinds(case: Case, kinds: &[IdentKind]) -> Code {
+        Code {
+            kinds: kinds.to_vec(),
+            ..Code::new(case)
+        }
+    }
+
+    #[test]
+    fn rust() {
+        test(
+            &Code::new(Case::Camel),
+            "lib.rs",
+            "struct user_info { user_name: String }\n\nimpl user_info {\n    fn get_name(&self, max_len: usize) -> String {\n        // user_name is kept\n        let mut full_name = self.user_name.clone();\n        full_name.truncate(max_len);\n        println!(\"{full_name} user_name\");\n        [1].iter().map(|item_count| item_count + 1);\n        full_name\n    }\n}\n",
+            "struct userInfo { userName: String }\n\nimpl userInfo {\n    fn getName(&self, maxLen: usize) -> String {\n        // user_name is kept\n        let mut full_name = self.userName.clone();\n        full_name.truncate(maxLen);\n        println!(\"{full_name} user_name\");\n        [1].iter().map(|itemCount| itemCount + 1);\n        full_name\n    }\n}\n",
+        );
+    }
+
+    #[test]
+    fn python() {
+        test(
+            &with_kinds(Case::Snake, &[IdentKind::Local, IdentKind::Function]),
+            "app.py",
+            "MAX_SIZE = 10\n\nclass UserInfo:\n    displayName: str = 'userName'\n\n    def getName(self, maxLen=MAX_SIZE):\n        fullName = self.displayName[:maxLen]\n        for charIndex in range(maxLen):\n            pass\n        return fullName\n\nprint(UserInfo().getName(lambda itemCount: itemCount))\n",
+            "MAX_SIZE = 10\n\nclass UserInfo:\n    displayName: str = 'userName'\n\n    def get_name(self, max_len=MAX_SIZE):\n        full_name = self.displayName[:max_len]\n        for char_index in range(max_len):\n            pass\n        return full_name\n\nprint(UserInfo().get_name(lambda item_count: item_count))\n",
+        );
+        test(
+            &Code::new(Case::Snake),
+            "app.py",
+            "MAX = 1\nMIN_SIZE = MAX\n",
+            "MAX = 1\nmin_size = MAX\n",
+        );
+    }
+
+    #[test]
+    #[expect(
+        clippy::literal_string_with_formatting_args,
+        reason = "the sources have format strings"
+    )]
+    fn interpolations() {
+        test(
+            &Code::new(Case::Snake),
+            "lib.rs",
+            "fn doThing(inputValue: u8, padWidth: usize) -> String {\n    let resultStr = format!(\"{inputValue:>padWidth$} getValue {{resultStr}}\");\n    resultStr\n}\n",
+            "fn do_thing(inputValue: u8, padWidth: usize) -> String {\n    let result_str = format!(\"{inputValue:>padWidth$} getValue {{resultStr}}\");\n    result_str\n}\n",
+        );
+        test(
+            &Code::new(Case::Snake),
+            "app.py",
+            "def getName(localVar, otherVar):\n    return f\"{localVar!r:>{otherVar}} x\" + \"{keptVar}\"\n\nkeptVar = 1\n",
+            "def get_name(localVar, otherVar):\n    return f\"{localVar!r:>{otherVar}} x\" + \"{keptVar}\"\n\nkept_var = 1\n",
+        );
+    }
+
+    #[test]
+    fn javascript() {
+        test(
+            &Code {
+                language: Some(Language::TypeScript),
+                ..with_kinds(Case::Camel, &[IdentKind::Local, IdentKind::Field])
+            },
+            "<stdin>",
+            "const user_list = [];\nclass user_store {\n  private max_size: number = 10;\n  add_user(user_name: string) {\n    const is_full = user_list.length >= this.max_size;\n    return { is_full, user_name: `${user_name}` };\n  }\n}\nconst on_click = (click_event) => click_event.target;\n",
+            "const userList = [];\nclass user_store {\n  private maxSize: number = 10;\n  add_user(userName: string) {\n    const isFull = userList.length >= this.maxSize;\n    return { isFull, userName: `${userName}` };\n  }\n}\nconst on_click = (clickEvent) => clickEvent.target;\n",
+        );
+    }
+
+    #[test]
+    fn members() {
+        test(
+            &with_kinds(Case::Snake, &[IdentKind::Function]),
+            "app.js",
+            "class Store {\n  getItem() { return this.getItem; }\n}\nfunction getItem() {}\nconst store = new Store();\nstore.getItem();\nnew Store().getItem();\nlocalStorage.getItem('a');\ngetItem();\n",
+            "class Store {\n  get_item() { return this.get_item; }\n}\nfunction get_item() {}\nconst store = new Store();\nstore.get_item();\nnew Store().get_item();\nlocalStorage.getItem('a');\nget_item();\n",
+        );
+    }
+
+    #[test]
+    fn go() {
+        test(
+            &with_kinds(Case::Pascal, &[IdentKind::Field, IdentKind::Function]),
+            "server.go",
+            "type server struct {\n\tlisten_addr string\n\tsync.Mutex\n}\n\nfunc (s *server) start_server(max_conns int) error {\n\tconn_count, err := s.accept(s.listen_addr)\n\treturn fmt.Errorf(\"%d\", conn_count)\n}\n",
+            "type server struct {\n\tListenAddr string\n\tsync.Mutex\n}\n\nfunc (s *server) StartServer(max_conns int) error {\n\tconn_count, err := s.accept(s.ListenAddr)\n\treturn fmt.Errorf(\"%d\", conn_count)\n}\n",
+        );
+    }
+
+    #[test]
+    fn c_header() {
+        test(
+            &Code {
+                pattern: Some(Pattern::Glob("*Count".chars().collect())),
+                ..Code::new(Case::Snake)
+            },
+            "list.h",
+            "#define maxCount 10\ntypedef struct node { int itemCount; struct node *nextNode; } nodeList;\n\nint countItems(nodeList *list, const char *itemName, int wordCount) {\n    int lineCount = wordCount * maxCount;\n    printf(\"lineCount\");\n    return lineCount + list->itemCount;\n}\n",
+            "#define max_count 10\ntypedef struct node { int item_count; struct node *nextNode; } nodeList;\n\nint countItems(nodeList *list, const char *itemName, int word_count) {\n    int line_count = word_count * max_count;\n    printf(\"lineCount\");\n    return line_count + list->item_count;\n}\n",
+        );
+    }
+
+    #[test]
+    fn private() {
+        let private = |language, text| {
+            declarations(language, text)
+                .into_iter()
+                .map(|declaration| (declaration.name, declaration.private))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            private(
+                Language::JavaScript,
+                "export function getA() {}\nfunction getB() {\n  const getC = () => 1;\n}\nexport class Store {\n  private size = 1;\n  count = 2;\n}\n"
+            ),
+            [
+                ("getA", false),
+                ("getB", true),
+                ("getC", true),
+                ("Store", false),
+                ("size", true),
+                ("count", false)
+            ]
+        );
+        assert_eq!(
+            private(
+                Language::Rust,
+                "pub struct Store;\nconst MAX: u8 = 1;\nimpl Store {\n    fn get(&self) {}\n}\n"
+            ),
+            [("Store", false), ("MAX", true), ("get", false)]
+        );
+        assert_eq!(
+            private(Language::C, "static int count;\nint total;\n"),
+            [("count", true), ("total", false)]
+        );
+        assert_eq!(
+            private(
+                Language::Python,
+                "LIMIT = 1\ndef run(count):\n    total = count\n"
+            ),
+            [
+                ("LIMIT", false),
+                ("run", false),
+                ("count", true),
+                ("total", true)
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_language() {
+        let mut out = vec![];
+        let err = Code::new(Case::Snake)
+            .recase("notes.txt", b"a".as_slice(), &mut out)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "can't tell the language from the file extension, use `--lang`"
+        );
+    }
+}
@@ -0,0 +1,166 @@
+/// Quote of the fields in CSV mode, doubled inside quoted fields.
+const QUOTE: char = '"';
+
+/// Selection of the fields of each record to convert, the rest being passed through unchanged.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fields {
+    /// Whether the fields can be quoted, like in CSV.
+    pub csv: bool,
+    /// Whether only the first record, the header, is converted.
+    pub header_only: bool,
+    /// Numbers of the fields to convert, counting from 1, or all of them if empty.
+    pub selected: Vec<usize>,
+    /// Separator of the fields.
+    pub separator: String,
+}
+
+impl Fields {
+    /// Converts the selected fields of the record with `convert`, and returns the record with the
+    /// other fields and the separators unchanged.
+    ///
+    /// In CSV mode, the converted fields are unquoted first, and quoted again if they were quoted
+    /// or if they need to be.
+    pub fn convert<Convert: Fn(&str) -> String>(
+        &self,
+        number: usize,
+        record: &str,
+        convert: Convert,
+    ) -> String {
+        if self.header_only && number != 1 {
+            return record.to_owned();
+        }
+        let mut output = String::with_capacity(record.len());
+        for (index, field) in (1..).zip(self.split(record)) {
+            if index != 1 {
+                output.push_str(&self.separator);
+            }
+            if !self.selected.is_empty() && !self.selected.contains(&index) {
+                output.push_str(field);
+            } else if self.csv {
+                output.push_str(&self.convert_quoted(field, &convert));
+            } else {
+                output.push_str(&convert(field));
+            }
+        }
+        output
+    }
+
+    /// Converts a field that may be quoted, and quotes it again if needed.
+    fn convert_quoted<Convert: Fn(&str) -> String>(&self, field: &str, convert: Convert) -> String {
+        let unquoted = field
+            .strip_prefix(QUOTE)
+            .and_then(|rest| rest.strip_suffix(QUOTE));
+        let converted = unquoted.map_or_else(
+            || convert(field),
+            |inner| convert(&inner.replace("\"\"", "\"")),
+        );
+        if unquoted.is_some()
+            || converted.contains(&self.separator)
+            || converted.contains([QUOTE, '\r', '\n'])
+        {
+            format!("\"{}\"", converted.replace('"', "\"\""))
+        } else {
+            converted
+        }
+    }
+
+    /// Splits the record into its fields, without splitting the quoted ones in CSV mode.
+    fn split<'record>(&self, record: &'record str) -> Vec<&'record str> {
+        let mut fields = vec![];
+        let mut rest = record;
+        let mut index: usize = 0;
+        let mut quoted = false;
+        while let Some(tail) = rest.get(index..) {
+            let Some(ch) = tail.chars().next() else {
+                break;
+            };
+            match ch {
+                QUOTE if self.csv => quoted = !quoted,
+                _ if !quoted && tail.starts_with(&self.separator) => {
+                    fields.push(rest.get(..index).unwrap_or_default());
+                    rest = tail.get(self.separator.len()..).unwrap_or_default();
+                    index = 0;
+                    continue;
+                }
+                _ => (),
+            }
+            index = index.saturating_add(ch.len_utf8());
+        }
+        fields.push(rest);
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fields;
+
+    fn test(fields: &Fields, records: &[(&str, &str)]) {
+        for (number, (record, expected)) in (1..).zip(records) {
+            assert_eq!(fields.convert(number, record, str::to_uppercase), *expected);
+        }
+    }
+
+    #[test]
+    fn separated() {
+        let fields = Fields {
+            csv: false,
+            header_only: false,
+            selected: vec![1, 3],
+            separator: "\t".to_owned(),
+        };
+        test(
+            &fields,
+            &[
+                ("a\tb\tc\td", "A\tb\tC\td"),
+                ("a\t\"b\tc\"", "A\t\"b\tC\""),
+                ("a", "A"),
+                ("", ""),
+            ],
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let fields = Fields {
+            csv: true,
+            header_only: false,
+            selected: vec![],
+            separator: ",".to_owned(),
+        };
+        test(
+            &fields,
+            &[
+                ("a,\"b, c\",d", "A,\"B, C\",D"),
+                ("\"say \"\"hi\"\"\",x", "\"SAY \"\"HI\"\"\",X"),
+                ("a,,\"\"", "A,,\"\""),
+                ("\"multi\nline\"", "\"MULTI\nLINE\""),
+            ],
+        );
+    }
+
+    #[test]
+    fn requoted() {
+        let fields = Fields {
+            csv: true,
+            header_only: false,
+            selected: vec![2],
+            separator: ";".to_owned(),
+        };
+        assert_eq!(
+            fields.convert(1, "a;b", |field| format!("{field};\"{field}\"")),
+            "a;\"b;\"\"b\"\"\""
+        );
+    }
+
+    #[test]
+    fn header_only() {
+        let fields = Fields {
+            csv: true,
+            header_only: true,
+            selected: vec![],
+            separator: ",".to_owned(),
+        };
+        test(&fields, &[("a,b", "A,B"), ("a,b", "a,b")]);
+    }
+}
@@ -0,0 +1,45 @@
+use std::fs::{self, File};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Replaces the contents of a file atomically, optionally keeping a backup of the original file.
+///
+/// The new contents are written to a temporary file in the same directory, that is then renamed
+/// over the original file, so the file is never left half-written. Symbolic links are followed,
+/// so the file they point to is replaced and they are kept. The backup, if any, is named after
+/// the given path with the given suffix appended.
+pub fn replace(path: &Path, contents: &[u8], backup_suffix: Option<&str>) -> io::Result<()> {
+    let target = fs::canonicalize(path)?;
+    let temp = temp_sibling(&target)?;
+    write_synced(&temp, contents)
+        .and_then(|()| fs::set_permissions(&temp, fs::metadata(&target)?.permissions()))
+        .and_then(|()| match backup_suffix {
+            Some(suffix) => {
+                fs::copy(&target, sibling(path, |name| format!("{name}{suffix}"))?).map(drop)
+            }
+            None => Ok(()),
+        })
+        .and_then(|()| fs::rename(&temp, &target))
+        .inspect_err(|_| drop(fs::remove_file(&temp)))
+}
+
+/// Returns the path of a file in the same directory as `path`, named after its file name.
+fn sibling(path: &Path, name: impl FnOnce(&str) -> String) -> io::Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file"))?;
+    Ok(path.with_file_name(name(&file_name.to_string_lossy())))
+}
+
+/// Returns a temporary path in the same directory as `path`, to write or rename it through.
+pub fn temp_sibling(path: &Path) -> io::Result<PathBuf> {
+    sibling(path, |name| format!(".{name}.caseify-{}", process::id()))
+}
+
+/// Writes the contents to a new file and flushes them to the disk.
+fn write_synced(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = File::create_new(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::io::{self, Read, Write};
+
+use crate::bin_helper::keys::{self, is_name};
+use crate::parser::case::Case;
+
+/// Syntax of the files whose keys are converted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    /// `.env` files: `KEY=value` lines, optionally after `export`, `#` comments, and quoted values
+    /// that can span several lines.
+    Env,
+    /// INI and properties files: `[section]` headers, `key=value` or `key: value` lines, `#`, `;`
+    /// and `!` comments, and values continued on the next line after a trailing backslash.
+    Ini,
+}
+
+impl Dialect {
+    /// Returns what ends the value started on the line if it continues on the next one: its
+    /// opening quote, or a backslash if it isn't followed by another one.
+    fn continuation(self, value: &str) -> Option<char> {
+        match self {
+            Self::Env => {
+                let quote = value
+                    .trim_start()
+                    .chars()
+                    .next()
+                    .filter(|ch| matches!(ch, '"' | '\''))?;
+                let rest = value.trim_start().get(1..).unwrap_or_default();
+                continues(rest, quote)
+            }
+            Self::Ini => continues(value, '\\'),
+        }
+    }
+
+    /// Returns the range of the key in the line, if it assigns one.
+    fn key_range(self, line: &str) -> Option<(usize, usize)> {
+        let body = line.trim_start();
+        let comments: &[char] = match self {
+            Self::Env => &['#'],
+            Self::Ini => &['#', ';', '!', '['],
+        };
+        if body.is_empty() || body.starts_with(comments) {
+            return None;
+        }
+        let exported = match self {
+            Self::Env => body
+                .strip_prefix("export")
+                .filter(|rest| rest.starts_with([' ', '\t']))
+                .map_or(body, str::trim_start),
+            Self::Ini => body,
+        };
+        let separators: &[char] = match self {
+            Self::Env => &['='],
+            Self::Ini => &['=', ':'],
+        };
+        let key = exported
+            .get(..exported.find(separators)?)?
+            .trim_end_matches([' ', '\t']);
+        let start = line.len().saturating_sub(exported.len());
+        (!key.is_empty()).then(|| (start, start.saturating_add(key.len())))
+    }
+}
+
+/// Converts the keys of the file of the reader to the case, and writes the file.
+///
+/// Only the keys are converted: the comments, section headers, `export` prefixes and values are
+/// written unchanged, even when they span several lines. The dotted names of `.env` files are
+/// joined into a single name, like `database.url` to `DATABASE_URL`, unless one of their parts
+/// isn't a name, like in `app.kubernetes.io/name`, while the parts of the keys of INI and
+/// properties files are converted one by one, keeping the dots and the parts that aren't names.
+/// Returns the descriptions of the keys of a
+/// section that are converted to the same key as a different one before them.
+pub fn recase<R: Read, W: Write>(
+    dialect: Dialect,
+    case: Case,
+    mut reader: R,
+    out: &mut W,
+) -> io::Result<Vec<String>> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    let mut output = String::with_capacity(text.len());
+    let mut collisions = vec![];
+    let mut keys: HashMap<String, (&str, usize)> = HashMap::new();
+    let mut continuation = None;
+    for (number, line) in (1..).zip(text.split_inclusive('\n')) {
+        if let Some(end) = continuation {
+            continuation = continues(line, end);
+            output.push_str(line);
+            continue;
+        }
+        if dialect == Dialect::Ini && line.trim_start().starts_with('[') {
+            keys.clear();
+        }
+        let Some((start, end)) = dialect.key_range(line) else {
+            output.push_str(line);
+            continue;
+        };
+        let before = line.get(..start).unwrap_or_default();
+        let key = line.get(start..end).unwrap_or_default();
+        let after = line.get(end..).unwrap_or_default();
+        let converted = match dialect {
+            Dialect::Env => Some(case.caseify(key))
+                .filter(|converted| !converted.is_empty() && key.split('.').all(is_name))
+                .unwrap_or_else(|| key.to_owned()),
+            Dialect::Ini => key
+                .split('.')
+                .map(|part| keys::convert_key(case, part).unwrap_or_else(|| part.to_owned()))
+                .collect::<Vec<_>>()
+                .join("."),
+        };
+        output.push_str(before);
+        output.push_str(&converted);
+        output.push_str(after);
+        let value = after
+            .trim_start_matches([' ', '\t'])
+            .get(1..)
+            .unwrap_or_default();
+        continuation = dialect.continuation(value);
+        match keys.entry(converted) {
+            Entry::Occupied(entry) if entry.get().0 != key => {
+                let (other, other_number) = entry.get();
+                collisions.push(format!(
+                    "line {number}: `{key}` and `{other}` on line {other_number} are both converted to `{}`",
+                    entry.key()
+                ));
+            }
+            Entry::Occupied(_) => (),
+            Entry::Vacant(entry) => {
+                entry.insert((key, number));
+            }
+        }
+    }
+    out.write_all(output.as_bytes())?;
+    Ok(collisions)
+}
+
+/// Returns `end` if the value doesn't end on the line, because it doesn't hold the closing
+/// quote, or because it ends with a backslash that isn't escaped.
+fn continues(line: &str, end: char) -> Option<char> {
+    if end == '\\' {
+        let content = line.trim_end_matches(['\r', '\n']);
+        let backslashes = content
+            .len()
+            .saturating_sub(content.trim_end_matches('\\').len());
+        return (!backslashes.is_multiple_of(2)).then_some(end);
+    }
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' if end == '"' => {
+                chars.next();
+            }
+            _ if ch == end => return None,
+            _ => (),
+        }
+    }
+    Some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "tests")]
+
+    use super::{Dialect, recase};
+    use crate::parser::case::Case;
+
+    fn test(dialect: Dialect, case: Case, input: &str, expected: &str) -> Vec<String> {
+        let mut out = vec![];
+        let collisions = recase(dialect, case, input.as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        collisions
+    }
+
+    #[test]
+    fn env() {
+        let collisions = test(
+            Dialect::Env,
+            Case::Constant,
+            "# database.url=x\ndatabase.url=postgres://a=b # comment\nexport  apiKey = 'secret'\n\nprivate.key=\"-----BEGIN\nnot.a=key\\\"\n-----END\"\nDATABASE_URL=y\r\n",
+            "# database.url=x\nDATABASE_URL=postgres://a=b # comment\nexport  API_KEY = 'secret'\n\nPRIVATE_KEY=\"-----BEGIN\nnot.a=key\\\"\n-----END\"\nDATABASE_URL=y\r\n",
+        );
+        assert_eq!(
+            collisions,
+            [
+                "line 8: `DATABASE_URL` and `database.url` on line 2 are both converted to `DATABASE_URL`"
+            ]
+        );
+    }
+
+    #[test]
+    fn ini() {
+        let collisions = test(
+            Dialect::Ini,
+            Case::Snake,
+            "; settings\n[Server.Main]\nhttpPort = 80\nhttp-port: 81\n\n[other]\n! comment\nhttpPort=1 \\\n  notAKey=2\nmaxConnections\napp.kubernetes.io/name=web\nlog4j.rootLogger=INFO\nother.keyName=1\n",
+            "; settings\n[Server.Main]\nhttp_port = 80\nhttp_port: 81\n\n[other]\n! comment\nhttp_port=1 \\\n  notAKey=2\nmaxConnections\napp.kubernetes.io/name=web\nlog4j.root_logger=INFO\nother.key_name=1\n",
+        );
+        assert_eq!(
+            collisions,
+            ["line 4: `http-port` and `httpPort` on line 3 are both converted to `http_port`"]
+        );
+    }
+}
@@ -0,0 +1,726 @@
+use core::fmt::{Display, Write as _};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::io::{self, BufRead, Write};
+
+use crate::bin_helper::keys;
+use crate::bin_helper::pattern::Pattern;
+use crate::parser::case::Case;
+
+/// Number of hexadecimal digits of a code unit in a unicode escape.
+const ESCAPE_DIGITS: usize = 4;
+
+/// Options to convert the keys of JSON documents to a case.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Json {
+    /// Case to convert the keys to.
+    pub case: Case,
+    /// Deepest level of the objects whose keys are converted, where the outermost objects are at
+    /// depth 1, or `None` to convert the keys at any depth.
+    pub depth: Option<usize>,
+    /// Patterns of the keys that are never converted.
+    pub exclude: Vec<Pattern>,
+    /// Patterns of the only keys that are converted, or all of them if empty.
+    pub include: Vec<Pattern>,
+    /// Layout of the output.
+    pub layout: Layout,
+    /// Whether to also convert the string values.
+    pub values: bool,
+}
+
+impl Json {
+    /// Returns `true` iff the key is converted, given the number of objects it is in, if it is a
+    /// name.
+    fn converts_key(&self, key: &str, objects: usize) -> bool {
+        self.is_within_depth(objects)
+            && (self.include.is_empty() || self.include.iter().any(|pattern| pattern.matches(key)))
+            && !self.exclude.iter().any(|pattern| pattern.matches(key))
+    }
+
+    /// Returns `true` iff the keys of the objects nested that deep are converted.
+    fn is_within_depth(&self, objects: usize) -> bool {
+        self.depth.is_none_or(|depth| objects <= depth)
+    }
+
+    /// Returns the options to convert all the keys to the given case, keeping the layout.
+    pub const fn new(case: Case) -> Self {
+        Self {
+            case,
+            depth: None,
+            exclude: vec![],
+            include: vec![],
+            layout: Layout::Preserve,
+            values: false,
+        }
+    }
+
+    /// Converts the keys of the JSON documents of the reader, and writes the documents.
+    ///
+    /// The keys that aren't names, like paths, domains or `$schema`, are kept as they are.
+    ///
+    /// The documents are streamed, so the input can hold any number of them, like JSON Lines.
+    /// Returns the descriptions of the keys of an object that are converted to the same key as a
+    /// different one before them. Fails with [`io::ErrorKind::InvalidData`] and the position of
+    /// the error if the input isn't valid JSON.
+    pub fn recase<R: BufRead, W: Write>(&self, reader: R, out: &mut W) -> io::Result<Vec<String>> {
+        let mut recaser = Recaser {
+            collisions: vec![],
+            expected: Expected::Value,
+            frames: vec![],
+            input: Input {
+                column: 1,
+                line: 1,
+                reader,
+            },
+            json: self,
+            objects: 0,
+            out,
+        };
+        recaser.run()?;
+        Ok(recaser.collisions)
+    }
+}
+
+/// Layout of the JSON output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Without whitespace between the tokens, and a document per line.
+    Compact,
+    /// With the whitespace of the input.
+    #[default]
+    Preserve,
+    /// Indented by two spaces, with a member or an element per line.
+    Pretty,
+}
+
+/// Token expected next by the [`Recaser`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Expected {
+    /// `:`, after a key.
+    Colon,
+    /// `,` or the end of the array or object, after a value.
+    CommaOrEnd,
+    /// Key, after a `,` in an object.
+    Key,
+    /// Key or `}`, at the start of an object.
+    KeyOrEnd,
+    /// Value, at the start of a document, after a `:` or after a `,` in an array.
+    Value,
+    /// Value or `]`, at the start of an array.
+    ValueOrEnd,
+}
+
+impl Expected {
+    /// Describes the expected token in error messages.
+    const fn describe(self) -> &'static str {
+        match self {
+            Self::Colon => "`:`",
+            Self::CommaOrEnd => "`,` or a closing bracket",
+            Self::Key => "a key",
+            Self::KeyOrEnd => "a key or `}`",
+            Self::Value => "a value",
+            Self::ValueOrEnd => "a value or `]`",
+        }
+    }
+}
+
+/// Array or object the [`Recaser`] is in.
+#[derive(Debug)]
+struct Frame {
+    /// Whether the container is empty so far.
+    is_empty: bool,
+    /// Whether the container is an object, or else an array.
+    is_object: bool,
+    /// Keys of the object so far, as written, with the key they were converted from and its line.
+    keys: HashMap<String, (String, usize)>,
+}
+
+/// Reader of the input, that keeps track of the position for error messages.
+struct Input<R> {
+    /// Column of the next byte, in bytes, counting from 1.
+    column: usize,
+    /// Line of the next byte, counting from 1.
+    line: usize,
+    /// Reader of the input.
+    reader: R,
+}
+
+impl<R: BufRead> Input<R> {
+    /// Returns an error at the position of the next byte.
+    fn error<Message: Display>(&self, message: Message) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("line {}, column {}: {message}", self.line, self.column),
+        )
+    }
+
+    /// Reads the next byte.
+    fn next(&mut self) -> io::Result<Option<u8>> {
+        let byte = self.peek()?;
+        if let Some(read) = byte {
+            self.reader.consume(1);
+            if read == b'\n' {
+                self.line = self.line.saturating_add(1);
+                self.column = 1;
+            } else {
+                self.column = self.column.saturating_add(1);
+            }
+        }
+        Ok(byte)
+    }
+
+    /// Returns the next byte without reading it.
+    fn peek(&mut self) -> io::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    /// Reads the next byte, and fails at the end of the input.
+    fn required(&mut self, context: &str) -> io::Result<u8> {
+        self.next()?
+            .ok_or_else(|| self.error(format!("unexpected end of input in {context}")))
+    }
+}
+
+/// Streaming parser of JSON documents, that writes them back with their keys converted.
+struct Recaser<'json, 'out, R, W> {
+    /// Descriptions of the keys converted to the same key as another one of their object.
+    collisions: Vec<String>,
+    /// Token expected next.
+    expected: Expected,
+    /// Arrays and objects the parser is in, from the outermost one.
+    frames: Vec<Frame>,
+    /// Input documents.
+    input: Input<R>,
+    /// Options of the conversion.
+    json: &'json Json,
+    /// Number of objects the parser is in.
+    objects: usize,
+    /// Where the documents are written.
+    out: &'out mut W,
+}
+
+impl<R: BufRead, W: Write> Recaser<'_, '_, R, W> {
+    /// Adds the key of the current object, read on the given line and written as `written`, and
+    /// notes if a different key before it was written the same.
+    fn add_key(&mut self, written: String, key: String, line: usize) {
+        let Some(frame) = self.frames.last_mut() else {
+            return;
+        };
+        match frame.keys.entry(written) {
+            Entry::Occupied(entry) if entry.get().0 != key => {
+                let (other, other_line) = entry.get();
+                self.collisions.push(format!(
+                    "line {line}: `{key}` and `{other}` on line {other_line} are both converted to `{}`",
+                    entry.key()
+                ));
+            }
+            Entry::Occupied(_) => (),
+            Entry::Vacant(entry) => {
+                entry.insert((key, line));
+            }
+        }
+    }
+
+    /// Starts a member of an object or an element of an array, on a new line if pretty.
+    fn begin_item(&mut self) -> io::Result<()> {
+        let Some(frame) = self.frames.last_mut() else {
+            return Ok(());
+        };
+        frame.is_empty = false;
+        if self.json.layout == Layout::Pretty {
+            self.write_indent(self.frames.len())?;
+        }
+        Ok(())
+    }
+
+    /// Closes the current array or object with the given bracket.
+    fn close(&mut self, bracket: u8) -> io::Result<()> {
+        let is_object = bracket == b'}';
+        let Some(frame) = self.frames.pop_if(|frame| frame.is_object == is_object) else {
+            return Err(self.input.error(format!(
+                "unexpected `{}`, expected {}",
+                char::from(bracket),
+                self.expected.describe()
+            )));
+        };
+        self.input.next()?;
+        if is_object {
+            self.objects = self.objects.saturating_sub(1);
+        }
+        if !frame.is_empty && self.json.layout == Layout::Pretty {
+            self.write_indent(self.frames.len())?;
+        }
+        self.out.write_all(&[bracket])?;
+        self.end_value()
+    }
+
+    /// Updates the expected token after a complete value, and separates the documents.
+    fn end_value(&mut self) -> io::Result<()> {
+        if self.frames.is_empty() {
+            self.expected = Expected::Value;
+            if self.json.layout != Layout::Preserve {
+                self.out.write_all(b"\n")?;
+            }
+        } else {
+            self.expected = Expected::CommaOrEnd;
+        }
+        Ok(())
+    }
+
+    /// Reads a literal, `true`, `false` or `null`, and writes it.
+    fn literal(&mut self) -> io::Result<()> {
+        let mut literal = vec![];
+        while let Some(byte) = self.input.peek()?.filter(u8::is_ascii_alphabetic) {
+            self.input.next()?;
+            literal.push(byte);
+        }
+        if ![b"true".as_slice(), b"false", b"null"].contains(&literal.as_slice()) {
+            return Err(self.input.error(format!(
+                "invalid literal `{}`",
+                String::from_utf8_lossy(&literal)
+            )));
+        }
+        self.out.write_all(&literal)?;
+        self.end_value()
+    }
+
+    /// Reads a number, and writes it unchanged.
+    fn number(&mut self) -> io::Result<()> {
+        let mut number = vec![];
+        if self.input.peek()? == Some(b'-') {
+            number.extend(self.input.next()?);
+        }
+        match self.input.peek()? {
+            Some(b'0') => number.extend(self.input.next()?),
+            Some(b'1'..=b'9') => self.read_digits(&mut number)?,
+            Some(_) | None => return Err(self.input.error("invalid number")),
+        }
+        if self.input.peek()? == Some(b'.') {
+            number.extend(self.input.next()?);
+            self.read_digits(&mut number)?;
+        }
+        if matches!(self.input.peek()?, Some(b'e' | b'E')) {
+            number.extend(self.input.next()?);
+            if matches!(self.input.peek()?, Some(b'+' | b'-')) {
+                number.extend(self.input.next()?);
+            }
+            self.read_digits(&mut number)?;
+        }
+        self.out.write_all(&number)?;
+        self.end_value()
+    }
+
+    /// Opens an array or an object with the given bracket.
+    fn open(&mut self, bracket: u8) -> io::Result<()> {
+        self.input.next()?;
+        self.out.write_all(&[bracket])?;
+        let is_object = bracket == b'{';
+        if is_object {
+            self.objects = self.objects.saturating_add(1);
+            self.expected = Expected::KeyOrEnd;
+        } else {
+            self.expected = Expected::ValueOrEnd;
+        }
+        self.frames.push(Frame {
+            is_empty: true,
+            is_object,
+            keys: HashMap::new(),
+        });
+        Ok(())
+    }
+
+    /// Reads the hexadecimal digits of a code unit, after `\u`.
+    fn read_code_unit(&mut self, raw: &mut Vec<u8>) -> io::Result<u32> {
+        let mut unit: u32 = 0;
+        for _ in 0..ESCAPE_DIGITS {
+            let byte = self.input.required("string")?;
+            raw.push(byte);
+            let digit = char::from(byte)
+                .to_digit(16)
+                .ok_or_else(|| self.input.error("invalid unicode escape in string"))?;
+            unit = unit.saturating_mul(16).saturating_add(digit);
+        }
+        Ok(unit)
+    }
+
+    /// Reads at least one digit.
+    fn read_digits(&mut self, number: &mut Vec<u8>) -> io::Result<()> {
+        let start = number.len();
+        while let Some(digit) = self.input.peek()?.filter(u8::is_ascii_digit) {
+            self.input.next()?;
+            number.push(digit);
+        }
+        if number.len() == start {
+            return Err(self.input.error("invalid number, expected a digit"));
+        }
+        Ok(())
+    }
+
+    /// Reads a string, and returns it as written and as decoded.
+    fn read_string(&mut self) -> io::Result<(Vec<u8>, String)> {
+        let mut raw = vec![];
+        let mut decoded = vec![];
+        raw.extend(self.input.next()?);
+        loop {
+            let byte = self.input.required("string")?;
+            raw.push(byte);
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = self.input.required("string")?;
+                    raw.push(escaped);
+                    let ch = match escaped {
+                        b'"' | b'\\' | b'/' => char::from(escaped),
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.read_unicode_escape(&mut raw)?,
+                        _ => return Err(self.input.error("invalid escape sequence in string")),
+                    };
+                    decoded.extend(ch.encode_utf8(&mut [0; 4]).bytes());
+                }
+                0..=0x1f => return Err(self.input.error("unescaped control character in string")),
+                _ => decoded.push(byte),
+            }
+        }
+        let text = String::from_utf8(decoded)
+            .map_err(|_invalid| self.input.error("invalid UTF-8 in string"))?;
+        Ok((raw, text))
+    }
+
+    /// Reads a unicode escape after `\u`, with the low surrogate of the pair if needed.
+    fn read_unicode_escape(&mut self, raw: &mut Vec<u8>) -> io::Result<char> {
+        let high = self.read_code_unit(raw)?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            for expected in *b"\\u" {
+                let byte = self.input.required("string")?;
+                raw.push(byte);
+                if byte != expected {
+                    return Err(self.input.error("unpaired surrogate in string"));
+                }
+            }
+            let low = self.read_code_unit(raw)?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.input.error("unpaired surrogate in string"));
+            }
+            high.saturating_sub(0xD800)
+                .saturating_mul(0x400)
+                .saturating_add(low.saturating_sub(0xDC00))
+                .saturating_add(0x1_0000)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.input.error("unpaired surrogate in string"))
+    }
+
+    /// Parses and writes the documents until the end of the input.
+    fn run(&mut self) -> io::Result<()> {
+        loop {
+            self.skip_whitespace()?;
+            let Some(byte) = self.input.peek()? else {
+                if self.frames.is_empty() && self.expected == Expected::Value {
+                    return self.out.flush();
+                }
+                return Err(self.input.error("unexpected end of input"));
+            };
+            match (self.expected, byte) {
+                (Expected::ValueOrEnd, b']')
+                | (Expected::KeyOrEnd, b'}')
+                | (Expected::CommaOrEnd, b']' | b'}') => self.close(byte)?,
+                (Expected::Value | Expected::ValueOrEnd, _) => self.value(byte)?,
+                (Expected::Key | Expected::KeyOrEnd, b'"') => {
+                    self.begin_item()?;
+                    let line = self.input.line;
+                    let (raw, key) = self.read_string()?;
+                    let converted = if self.json.converts_key(&key, self.objects) {
+                        keys::convert_key(self.json.case, &key)
+                    } else {
+                        None
+                    };
+                    let written = self.write_string(&raw, &key, converted)?;
+                    self.add_key(written, key, line);
+                    self.expected = Expected::Colon;
+                }
+                (Expected::Colon, b':') => {
+                    self.input.next()?;
+                    self.out.write_all(if self.json.layout == Layout::Pretty {
+                        b": "
+                    } else {
+                        b":"
+                    })?;
+                    self.expected = Expected::Value;
+                }
+                (Expected::CommaOrEnd, b',') => {
+                    self.input.next()?;
+                    self.out.write_all(b",")?;
+                    let in_object = self.frames.last().is_some_and(|frame| frame.is_object);
+                    self.expected = if in_object {
+                        Expected::Key
+                    } else {
+                        Expected::Value
+                    };
+                }
+                (
+                    Expected::Colon | Expected::CommaOrEnd | Expected::Key | Expected::KeyOrEnd,
+                    _,
+                ) => {
+                    return Err(self.input.error(format!(
+                        "unexpected `{}`, expected {}",
+                        char::from(byte).escape_default(),
+                        self.expected.describe()
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Skips the whitespace, and writes it if the layout is preserved.
+    fn skip_whitespace(&mut self) -> io::Result<()> {
+        while let Some(byte) = self
+            .input
+            .peek()?
+            .filter(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+        {
+            self.input.next()?;
+            if self.json.layout == Layout::Preserve {
+                self.out.write_all(&[byte])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a value starting with the given byte, and writes it, or opens it if it is an array
+    /// or an object.
+    fn value(&mut self, byte: u8) -> io::Result<()> {
+        if self.frames.last().is_some_and(|frame| !frame.is_object) {
+            self.begin_item()?;
+        }
+        match byte {
+            b'{' | b'[' => self.open(byte),
+            b'"' => {
+                let (raw, value) = self.read_string()?;
+                let converts = self.json.values && self.json.is_within_depth(self.objects);
+                let converted = converts.then(|| self.json.case.caseify(&value));
+                self.write_string(&raw, &value, converted)?;
+                self.end_value()
+            }
+            b'-' | b'0'..=b'9' => self.number(),
+            b't' | b'f' | b'n' => self.literal(),
+            _ => Err(self.input.error(format!(
+                "unexpected `{}`, expected {}",
+                char::from(byte).escape_default(),
+                self.expected.describe()
+            ))),
+        }
+    }
+
+    /// Writes a new line, indented for the given number of containers.
+    fn write_indent(&mut self, level: usize) -> io::Result<()> {
+        self.out.write_all(b"\n")?;
+        for _ in 0..level {
+            self.out.write_all(b"  ")?;
+        }
+        Ok(())
+    }
+
+    /// Writes a string, converted if given, or as it was written if it doesn't change, and
+    /// returns the text written.
+    fn write_string(
+        &mut self,
+        raw: &[u8],
+        text: &str,
+        converted: Option<String>,
+    ) -> io::Result<String> {
+        if let Some(changed) = converted.filter(|changed| changed != text) {
+            self.out.write_all(encode(&changed).as_bytes())?;
+            Ok(changed)
+        } else {
+            self.out.write_all(raw)?;
+            Ok(text.to_owned())
+        }
+    }
+}
+
+/// Encodes the text as a JSON string, with its quotes.
+fn encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len().saturating_add(2));
+    encoded.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            '\u{0}'..='\u{1f}' => {
+                let _infallible = write!(encoded, "\\u{:04x}", u32::from(ch));
+            }
+            _ => encoded.push(ch),
+        }
+    }
+    encoded.push('"');
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "tests")]
+
+    use super::{Json, Layout};
+    use crate::bin_helper::pattern::Pattern;
+    use crate::parser::case::Case;
+
+    const DOCUMENT: &str = "{\"userName\": \"Ann Lee\",\n \"homeAddress\": {\"zipCode\": 1.5e3, \"tags\": [{\"aB\": null}, true, []]}}";
+
+    fn recase(json: &Json, input: &str) -> Result<String, String> {
+        let mut out = vec![];
+        json.recase(input.as_bytes(), &mut out)
+            .map_err(|err| err.to_string())?;
+        String::from_utf8(out).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn preserve() {
+        assert_eq!(
+            recase(&Json::new(Case::Snake), DOCUMENT).unwrap(),
+            "{\"user_name\": \"Ann Lee\",\n \"home_address\": {\"zip_code\": 1.5e3, \"tags\": [{\"a_b\": null}, true, []]}}"
+        );
+        assert_eq!(
+            recase(&Json::new(Case::Kebab), "{\"a\":1}\n{\"bC\":{}} [\"dE\"]\n").unwrap(),
+            "{\"a\":1}\n{\"b-c\":{}} [\"dE\"]\n"
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let json = Json {
+            layout: Layout::Pretty,
+            ..Json::new(Case::Camel)
+        };
+        assert_eq!(
+            recase(&json, "{\"user_name\":[1,{}],\"e\":{}} []").unwrap(),
+            "{\n  \"userName\": [\n    1,\n    {}\n  ],\n  \"e\": {}\n}\n[]\n"
+        );
+    }
+
+    #[test]
+    fn compact() {
+        let json = Json {
+            layout: Layout::Compact,
+            ..Json::new(Case::Constant)
+        };
+        assert_eq!(
+            recase(&json, DOCUMENT).unwrap(),
+            "{\"USER_NAME\":\"Ann Lee\",\"HOME_ADDRESS\":{\"ZIP_CODE\":1.5e3,\"TAGS\":[{\"A_B\":null},true,[]]}}\n"
+        );
+    }
+
+    #[test]
+    fn depth_and_values() {
+        let json = Json {
+            depth: Some(1),
+            values: true,
+            ..Json::new(Case::Snake)
+        };
+        assert_eq!(
+            recase(&json, DOCUMENT).unwrap(),
+            "{\"user_name\": \"ann_lee\",\n \"home_address\": {\"zipCode\": 1.5e3, \"tags\": [{\"aB\": null}, true, []]}}"
+        );
+    }
+
+    #[test]
+    fn patterns() {
+        let json = Json {
+            exclude: vec![Pattern::Glob("zip*".chars().collect())],
+            include: vec![
+                Pattern::Glob("*e*".chars().collect()),
+                Pattern::Glob("aB".chars().collect()),
+            ],
+            ..Json::new(Case::Snake)
+        };
+        assert_eq!(
+            recase(&json, DOCUMENT).unwrap(),
+            "{\"user_name\": \"Ann Lee\",\n \"home_address\": {\"zipCode\": 1.5e3, \"tags\": [{\"a_b\": null}, true, []]}}"
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            recase(
+                &Json::new(Case::Snake),
+                r#"{"caf\u00e9Name": "\ud83d\ude00", "keep\"Quote": "a\/b", "a\u005fb": 1}"#
+            )
+            .unwrap(),
+            "{\"caf\u{e9}_name\": \"\\ud83d\\ude00\", \"keep\\\"Quote\": \"a\\/b\", \"a\\u005fb\": 1}"
+        );
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            recase(
+                &Json::new(Case::Snake),
+                r#"{"metadata": {"labels": {"app.kubernetes.io/name": "x"}}, "$schema": "s", "@id": 1, "_": 2, "fooBar": 3}"#
+            )
+            .unwrap(),
+            r#"{"metadata": {"labels": {"app.kubernetes.io/name": "x"}}, "$schema": "s", "@id": 1, "_": 2, "foo_bar": 3}"#
+        );
+    }
+
+    #[test]
+    fn collisions() {
+        let mut out = vec![];
+        let collisions = Json::new(Case::Snake)
+            .recase(
+                b"{\"userId\": 1,\n \"user_id\": {\"user_id\": 2}, \"userId\": 3}".as_slice(),
+                &mut out,
+            )
+            .unwrap();
+        assert_eq!(
+            collisions,
+            ["line 2: `user_id` and `userId` on line 1 are both converted to `user_id`"]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let json = Json::new(Case::Snake);
+        for (input, message) in [
+            (
+                "{\"a\" 1}",
+                "line 1, column 6: unexpected `1`, expected `:`",
+            ),
+            ("[1,]", "line 1, column 4: unexpected `]`, expected a value"),
+            (
+                "[1}",
+                "line 1, column 3: unexpected `}`, expected `,` or a closing bracket",
+            ),
+            (
+                "{\n  \"a\": tru}",
+                "line 2, column 11: invalid literal `tru`",
+            ),
+            (
+                "{\"a\": 01}",
+                "line 1, column 8: unexpected `1`, expected `,` or a closing bracket",
+            ),
+            (
+                "[\"a\tb\"]",
+                "line 1, column 5: unescaped control character in string",
+            ),
+            (
+                "[\"\\ud800\"]",
+                "line 1, column 10: unpaired surrogate in string",
+            ),
+            ("{\"a\": [", "line 1, column 8: unexpected end of input"),
+            (
+                "\"abc",
+                "line 1, column 5: unexpected end of input in string",
+            ),
+        ] {
+            assert_eq!(recase(&json, input), Err(message.to_owned()), "{input}");
+        }
+    }
+}
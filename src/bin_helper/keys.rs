@@ -0,0 +1,51 @@
+use crate::parser::case::Case;
+
+/// Converts a key of a configuration file, bare or between quotes, and returns it between the same
+/// quotes, or `None` if it isn't a name.
+pub fn convert_key(case: Case, key: &str) -> Option<String> {
+    let quote = key.chars().next().filter(|ch| matches!(ch, '"' | '\''));
+    let name = match quote {
+        Some(ch) => key.strip_prefix(ch)?.strip_suffix(ch)?,
+        None => key,
+    };
+    if !is_name(name) {
+        return None;
+    }
+    let converted = case.caseify(name);
+    match quote {
+        _ if converted.is_empty() => None,
+        Some(ch) => Some(format!("{ch}{converted}{ch}")),
+        None => Some(converted),
+    }
+}
+
+/// Returns `true` iff the text is a name made of words, like the keys of configuration files, and
+/// not something like a path, a domain or an address that converting would break.
+///
+/// Words mixing letters and digits, like in `x86_64-unknown-linux-gnu`, are identifiers that
+/// converting would split, so the texts with such words aren't names either.
+pub fn is_name(text: &str) -> bool {
+    text.chars().any(char::is_alphabetic)
+        && text
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '-' | ' '))
+        && text.split(['_', '-', ' ']).all(|word| {
+            !(word.chars().any(char::is_alphabetic) && word.chars().any(char::is_numeric))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_name;
+
+    #[test]
+    fn names() {
+        assert!(is_name("userName"));
+        assert!(is_name("user_id-2 Name"));
+        assert!(!is_name("app.kubernetes.io/name"));
+        assert!(!is_name("x86_64-unknown-linux-gnu"));
+        assert!(!is_name("127"));
+        assert!(!is_name("<<"));
+        assert!(!is_name(""));
+    }
+}
@@ -0,0 +1,701 @@
+use core::str::FromStr;
+use std::path::Path;
+
+use crate::parser::convention::Convention;
+
+/// Keywords of C, with the built-in types and the preprocessor directives.
+const C_KEYWORDS: &[&str] = &[
+    "auto", "bool", "break", "case", "char", "const", "continue", "default", "define", "defined",
+    "do", "double", "elif", "else", "endif", "enum", "error", "extern", "false", "float", "for",
+    "goto", "if", "ifdef", "ifndef", "include", "inline", "int", "long", "NULL", "pragma",
+    "register", "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch",
+    "true", "typedef", "undef", "union", "unsigned", "void", "volatile", "while",
+];
+
+/// Keywords of Go, with the predeclared types, constants and functions.
+const GO_KEYWORDS: &[&str] = &[
+    "any",
+    "append",
+    "bool",
+    "break",
+    "byte",
+    "cap",
+    "case",
+    "chan",
+    "clear",
+    "close",
+    "comparable",
+    "complex",
+    "complex128",
+    "complex64",
+    "const",
+    "continue",
+    "copy",
+    "default",
+    "defer",
+    "delete",
+    "else",
+    "error",
+    "fallthrough",
+    "false",
+    "float32",
+    "float64",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "imag",
+    "import",
+    "int",
+    "int16",
+    "int32",
+    "int64",
+    "int8",
+    "interface",
+    "iota",
+    "len",
+    "make",
+    "map",
+    "max",
+    "min",
+    "new",
+    "nil",
+    "package",
+    "panic",
+    "print",
+    "println",
+    "range",
+    "real",
+    "recover",
+    "return",
+    "rune",
+    "select",
+    "string",
+    "struct",
+    "switch",
+    "true",
+    "type",
+    "uint",
+    "uint16",
+    "uint32",
+    "uint64",
+    "uint8",
+    "uintptr",
+    "var",
+];
+
+/// Keywords of JavaScript and TypeScript, with the built-in types of TypeScript.
+const JS_KEYWORDS: &[&str] = &[
+    "abstract",
+    "any",
+    "as",
+    "async",
+    "await",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "constructor",
+    "continue",
+    "debugger",
+    "declare",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "get",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "keyof",
+    "let",
+    "namespace",
+    "never",
+    "new",
+    "null",
+    "number",
+    "of",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "set",
+    "static",
+    "string",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "unknown",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Keywords of Python, with the built-in functions and types and the conventional names of the
+/// first parameter of methods.
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False",
+    "None",
+    "True",
+    "abs",
+    "aiter",
+    "all",
+    "and",
+    "anext",
+    "any",
+    "as",
+    "ascii",
+    "assert",
+    "async",
+    "await",
+    "bin",
+    "bool",
+    "break",
+    "breakpoint",
+    "bytearray",
+    "bytes",
+    "callable",
+    "chr",
+    "class",
+    "classmethod",
+    "cls",
+    "compile",
+    "complex",
+    "continue",
+    "def",
+    "del",
+    "delattr",
+    "dict",
+    "dir",
+    "divmod",
+    "elif",
+    "else",
+    "enumerate",
+    "eval",
+    "except",
+    "exec",
+    "filter",
+    "finally",
+    "float",
+    "for",
+    "format",
+    "from",
+    "frozenset",
+    "getattr",
+    "global",
+    "globals",
+    "hasattr",
+    "hash",
+    "help",
+    "hex",
+    "id",
+    "if",
+    "import",
+    "in",
+    "input",
+    "int",
+    "is",
+    "isinstance",
+    "issubclass",
+    "iter",
+    "lambda",
+    "len",
+    "list",
+    "locals",
+    "map",
+    "max",
+    "memoryview",
+    "min",
+    "next",
+    "nonlocal",
+    "not",
+    "object",
+    "oct",
+    "open",
+    "or",
+    "ord",
+    "pass",
+    "pow",
+    "print",
+    "property",
+    "raise",
+    "range",
+    "repr",
+    "return",
+    "reversed",
+    "round",
+    "self",
+    "set",
+    "setattr",
+    "slice",
+    "sorted",
+    "staticmethod",
+    "str",
+    "sum",
+    "super",
+    "try",
+    "tuple",
+    "type",
+    "vars",
+    "while",
+    "with",
+    "yield",
+    "zip",
+];
+
+/// Keywords of Rust, with the primitive types.
+const RUST_KEYWORDS: &[&str] = &[
+    "Self", "as", "async", "await", "bool", "break", "char", "const", "continue", "crate", "dyn",
+    "else", "enum", "extern", "f32", "f64", "false", "fn", "for", "i128", "i16", "i32", "i64",
+    "i8", "if", "impl", "in", "isize", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "static", "str", "struct", "super", "trait", "true", "type", "u128", "u16",
+    "u32", "u64", "u8", "union", "unsafe", "use", "usize", "where", "while",
+];
+
+/// Programming language of source files.
+#[expect(clippy::min_ident_chars, reason = "`C` is the name of the language")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    /// C, with its headers.
+    C,
+    /// Go.
+    Go,
+    /// JavaScript, with JSX.
+    JavaScript,
+    /// Python.
+    Python,
+    /// Rust.
+    Rust,
+    /// TypeScript, with TSX.
+    TypeScript,
+}
+
+impl Language {
+    /// Returns the naming convention of the language.
+    pub const fn convention(self) -> Convention {
+        match self {
+            Self::C => Convention::C,
+            Self::Go => Convention::Go,
+            Self::JavaScript | Self::TypeScript => Convention::JavaScript,
+            Self::Python => Convention::Python,
+            Self::Rust => Convention::Rust,
+        }
+    }
+
+    /// Returns the language of the file, from the extension of its path.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?;
+        match extension {
+            "c" | "h" => Some(Self::C),
+            "go" => Some(Self::Go),
+            "cjs" | "js" | "jsx" | "mjs" => Some(Self::JavaScript),
+            "py" | "pyi" => Some(Self::Python),
+            "rs" => Some(Self::Rust),
+            "cts" | "mts" | "ts" | "tsx" => Some(Self::TypeScript),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` iff the word is a keyword, or a built-in name that is never converted.
+    pub fn is_keyword(self, word: &str) -> bool {
+        match self {
+            Self::C => C_KEYWORDS,
+            Self::Go => GO_KEYWORDS,
+            Self::JavaScript | Self::TypeScript => JS_KEYWORDS,
+            Self::Python => PYTHON_KEYWORDS,
+            Self::Rust => RUST_KEYWORDS,
+        }
+        .contains(&word)
+    }
+
+    /// Returns `true` iff the prefix of a string, like `r` or `br` in Rust or `f` in Python, can
+    /// precede the quote.
+    fn is_string_prefix(self, prefix: &str, quote: char) -> bool {
+        match self {
+            Self::Python => {
+                matches!(quote, '"' | '\'')
+                    && ["b", "br", "f", "fr", "r", "rb", "rf", "u"]
+                        .contains(&prefix.to_lowercase().as_str())
+            }
+            Self::Rust => {
+                (matches!(prefix, "b" | "c") && matches!(quote, '"' | '\''))
+                    || (matches!(prefix, "br" | "cr" | "r") && matches!(quote, '"' | '#'))
+            }
+            Self::C | Self::Go | Self::JavaScript | Self::TypeScript => false,
+        }
+    }
+
+    /// Returns `true` iff the character can start an identifier.
+    fn starts_identifier(self, ch: char) -> bool {
+        ch.is_alphabetic()
+            || ch == '_'
+            || (ch == '$' && matches!(self, Self::JavaScript | Self::TypeScript))
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().as_str() {
+            "c" => Ok(Self::C),
+            "go" => Ok(Self::Go),
+            "javascript" | "js" => Ok(Self::JavaScript),
+            "py" | "python" => Ok(Self::Python),
+            "rs" | "rust" => Ok(Self::Rust),
+            "ts" | "typescript" => Ok(Self::TypeScript),
+            _ => Err(format!(
+                "Invalid value `{name}` for `--lang`, expected `c`, `go`, `js`, `py`, `rust` or `ts`"
+            )),
+        }
+    }
+}
+
+/// Token of source code, whose texts make up the whole source when concatenated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'text> {
+    /// Kind of the token.
+    pub kind: TokenKind,
+    /// Text of the token in the source.
+    pub text: &'text str,
+}
+
+/// Kind of a [`Token`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Line or block comment.
+    Comment,
+    /// Name that isn't a keyword.
+    Identifier,
+    /// Keyword or built-in name.
+    Keyword,
+    /// Number literal.
+    Number,
+    /// Operator or delimiter, a character at a time.
+    Punctuation,
+    /// String or character literal, with its prefix and quotes.
+    String,
+    /// Spaces and line breaks.
+    Whitespace,
+}
+
+/// Returns the length of the comment at the start of the text, if it starts with one.
+///
+/// Block comments nest in Rust.
+fn comment_len(language: Language, text: &str) -> Option<usize> {
+    let line_comment = if language == Language::Python {
+        "#"
+    } else {
+        "//"
+    };
+    if text.starts_with(line_comment) {
+        return Some(text.find('\n').unwrap_or(text.len()));
+    }
+    if language == Language::Python || !text.starts_with("/*") {
+        return None;
+    }
+    let mut depth: usize = 0;
+    let mut index: usize = 0;
+    while let Some(rest) = text.get(index..).filter(|rest| !rest.is_empty()) {
+        if rest.starts_with("/*") && (depth == 0 || language == Language::Rust) {
+            depth = depth.saturating_add(1);
+            index = index.saturating_add(2);
+        } else if rest.starts_with("*/") {
+            depth = depth.saturating_sub(1);
+            index = index.saturating_add(2);
+            if depth == 0 {
+                return Some(index);
+            }
+        } else {
+            index = index.saturating_add(rest.chars().next().map_or(1, char::len_utf8));
+        }
+    }
+    Some(text.len())
+}
+
+/// Splits the source code into tokens.
+///
+/// The lexer only tells identifiers apart from the rest: unknown syntax is split into
+/// punctuation, and unterminated strings or comments last until the end of the source.
+pub fn lex(language: Language, source: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut templates: Vec<usize> = vec![];
+    let mut rest = source;
+    while let Some(first) = rest.chars().next() {
+        let is_template = matches!(language, Language::JavaScript | Language::TypeScript)
+            && (first == '`' || (first == '}' && templates.last() == Some(&0)));
+        let (kind, length) = if is_template {
+            if first == '}' {
+                templates.pop();
+            }
+            let (length, interpolates) = template_len(rest);
+            if interpolates {
+                templates.push(0);
+            }
+            (TokenKind::String, length)
+        } else if first.is_whitespace() {
+            (TokenKind::Whitespace, prefix_len(rest, char::is_whitespace))
+        } else if let Some(length) = comment_len(language, rest) {
+            (TokenKind::Comment, length)
+        } else if let Some(length) = string_len(language, rest) {
+            (TokenKind::String, length)
+        } else if language.starts_identifier(first) {
+            let length = prefix_len(rest, |ch| {
+                ch.is_alphanumeric() || ch == '_' || language.starts_identifier(ch)
+            });
+            let word = rest.get(..length).unwrap_or_default();
+            let after = rest.get(length..).unwrap_or_default();
+            match after.chars().next() {
+                Some(quote) if language.is_string_prefix(word, quote) => {
+                    let string = if language == Language::Rust && word.ends_with('r') {
+                        raw_string_len(after)
+                    } else {
+                        string_len(language, after)
+                    };
+                    string.map_or((TokenKind::Identifier, length), |string_length| {
+                        (TokenKind::String, length.saturating_add(string_length))
+                    })
+                }
+                _ if language.is_keyword(word) => (TokenKind::Keyword, length),
+                _ => (TokenKind::Identifier, length),
+            }
+        } else if first.is_ascii_digit() {
+            (TokenKind::Number, number_len(rest))
+        } else {
+            if let Some(braces) = templates.last_mut() {
+                match first {
+                    '{' => *braces = braces.saturating_add(1),
+                    '}' => *braces = braces.saturating_sub(1),
+                    _ => (),
+                }
+            }
+            (TokenKind::Punctuation, first.len_utf8())
+        };
+        let (text, after) = rest.split_at_checked(length).unwrap_or((rest, ""));
+        tokens.push(Token { kind, text });
+        rest = after;
+    }
+    tokens
+}
+
+/// Returns the length of the number at the start of the text, with its suffix and decimals.
+fn number_len(text: &str) -> usize {
+    let mut previous = '0';
+    for (index, ch) in text.char_indices() {
+        let next = text
+            .get(index.saturating_add(1)..)
+            .and_then(|rest| rest.chars().next());
+        let is_decimal_point = ch == '.'
+            && previous.is_ascii_digit()
+            && next.is_some_and(|digit| digit.is_ascii_digit());
+        if !(ch.is_alphanumeric() || ch == '_' || is_decimal_point) {
+            return index;
+        }
+        previous = ch;
+    }
+    text.len()
+}
+
+/// Returns the length of the prefix of the text whose characters match the predicate.
+fn prefix_len<Predicate: Fn(char) -> bool>(text: &str, predicate: Predicate) -> usize {
+    text.find(|ch| !predicate(ch)).unwrap_or(text.len())
+}
+
+/// Returns the length of the raw string of Rust at the start of the text, after its prefix, if it
+/// starts with one: `"` after as many `#` as end it.
+fn raw_string_len(text: &str) -> Option<usize> {
+    let hashes = prefix_len(text, |ch| ch == '#');
+    if !text.get(hashes..)?.starts_with('"') {
+        return None;
+    }
+    let closing = format!("\"{}", "#".repeat(hashes));
+    let opening = hashes.saturating_add(1);
+    let body = text.get(opening..)?;
+    Some(body.find(&closing).map_or(text.len(), |end| {
+        opening.saturating_add(end).saturating_add(closing.len())
+    }))
+}
+
+/// Returns the length of the string or character literal at the start of the text, if it starts
+/// with one, after its prefix.
+///
+/// Strings that can't span several lines end at the end of the line if they aren't closed.
+fn string_len(language: Language, text: &str) -> Option<usize> {
+    let first = text.chars().next()?;
+    let (delimiter, escapes, multiline) = match (language, first) {
+        (Language::Rust, '\'') => {
+            let mut chars = text.chars().skip(1);
+            let is_char = match chars.next()? {
+                '\\' => true,
+                _ => chars.next() == Some('\''),
+            };
+            if !is_char {
+                return None;
+            }
+            ("'".to_owned(), true, false)
+        }
+        (Language::Python, '"' | '\'') => {
+            let triple = first.to_string().repeat(3);
+            if text.starts_with(&triple) {
+                (triple, true, true)
+            } else {
+                (first.to_string(), true, false)
+            }
+        }
+        (Language::Go, '`') => ("`".to_owned(), false, true),
+        (_, '"' | '\'') => (first.to_string(), true, language == Language::Rust),
+        _ => return None,
+    };
+    let body = text.get(delimiter.len()..)?;
+    let mut is_escaped = false;
+    for (index, ch) in body.char_indices() {
+        match ch {
+            _ if is_escaped => is_escaped = false,
+            '\\' if escapes => is_escaped = true,
+            '\n' if !multiline => return Some(delimiter.len().saturating_add(index)),
+            _ if body
+                .get(index..)
+                .is_some_and(|rest| rest.starts_with(delimiter.as_str())) =>
+            {
+                return Some(delimiter.len().saturating_mul(2).saturating_add(index));
+            }
+            _ => (),
+        }
+    }
+    Some(text.len())
+}
+
+/// Returns the length of the part of a JavaScript template at the start of the text, from its
+/// backtick or from the `}` ending an interpolation, and whether it ends by starting another
+/// interpolation.
+fn template_len(text: &str) -> (usize, bool) {
+    let body = text.get(1..).unwrap_or_default();
+    let mut is_escaped = false;
+    for (index, ch) in body.char_indices() {
+        match ch {
+            _ if is_escaped => is_escaped = false,
+            '\\' => is_escaped = true,
+            '`' => return (index.saturating_add(2), false),
+            '$' if body.get(index..).is_some_and(|rest| rest.starts_with("${")) => {
+                return (index.saturating_add(3), true);
+            }
+            _ => (),
+        }
+    }
+    (text.len(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Language, TokenKind, lex};
+
+    /// Returns the texts of the tokens of the given kind.
+    fn texts(language: Language, source: &str, kind: TokenKind) -> Vec<&str> {
+        let tokens = lex(language, source);
+        assert_eq!(
+            tokens.iter().map(|token| token.text).collect::<String>(),
+            source
+        );
+        tokens
+            .iter()
+            .filter(|token| token.kind == kind)
+            .map(|token| token.text)
+            .collect()
+    }
+
+    #[test]
+    fn rust() {
+        let source = "fn getName<'a>(x: &'a str) -> char { /* a /* nested */ comment */ let s = r#\"raw \"str\"\"#; 'c'; b'\\n'; 1.5e3_f64; x.len() } // end";
+        assert_eq!(
+            texts(Language::Rust, source, TokenKind::Identifier),
+            ["getName", "a", "x", "a", "s", "x", "len"]
+        );
+        assert_eq!(
+            texts(Language::Rust, source, TokenKind::String),
+            ["r#\"raw \"str\"\"#", "'c'", "b'\\n'"]
+        );
+        assert_eq!(
+            texts(Language::Rust, source, TokenKind::Comment),
+            ["/* a /* nested */ comment */", "// end"]
+        );
+    }
+
+    #[test]
+    fn python() {
+        let source = "def get_name(self):\n    '''Doc with 'quotes'\n    '''\n    return f\"{self.first_name}\" # done\n";
+        assert_eq!(
+            texts(Language::Python, source, TokenKind::Identifier),
+            ["get_name"]
+        );
+        assert_eq!(
+            texts(Language::Python, source, TokenKind::String),
+            ["'''Doc with 'quotes'\n    '''", "f\"{self.first_name}\""]
+        );
+    }
+
+    #[test]
+    fn others() {
+        assert_eq!(
+            texts(
+                Language::TypeScript,
+                "const $userName: string = `a ${b + {c: `${d}`}.c}\n`; /* x */",
+                TokenKind::Identifier
+            ),
+            ["$userName", "b", "c", "d", "c"]
+        );
+        assert_eq!(
+            texts(
+                Language::Go,
+                "msg := `raw\\` + \"a\\\"b\" // c",
+                TokenKind::String
+            ),
+            ["`raw\\`", "\"a\\\"b\""]
+        );
+        assert_eq!(
+            texts(
+                Language::C,
+                "#include <stdio.h>\nint main_loop(void) { return 'x'; }",
+                TokenKind::Identifier
+            ),
+            ["stdio", "h", "main_loop"]
+        );
+    }
+
+    #[test]
+    fn languages() {
+        assert_eq!(Language::from_path("src/main.rs"), Some(Language::Rust));
+        assert_eq!(
+            Language::from_path("app.test.tsx"),
+            Some(Language::TypeScript)
+        );
+        assert_eq!(Language::from_path("Makefile"), None);
+        assert_eq!("Python".parse(), Ok(Language::Python));
+        assert_eq!(
+            "cobol".parse::<Language>(),
+            Err("Invalid value `cobol` for `--lang`, expected `c`, `go`, `js`, `py`, `rust` or `ts`".to_owned())
+        );
+    }
+}
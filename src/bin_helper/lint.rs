@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::bin_helper::code::{self, Declaration};
+use crate::bin_helper::files;
+use crate::bin_helper::lexer::Language;
+use crate::bin_helper::status::Status;
+use crate::parser::convention::IdentKind;
+
+/// Kinds of the names checked unless `--kind` is given: those that other files see.
+const DEFAULT_KINDS: &[IdentKind] = &[
+    IdentKind::Constant,
+    IdentKind::EnumVariant,
+    IdentKind::FileName,
+    IdentKind::Function,
+    IdentKind::Type,
+];
+
+/// Options to check that the names of source files follow the convention of their language.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lint {
+    /// Whether to rename the declarations that break the convention and that other files can't
+    /// see, and their uses.
+    pub fix: bool,
+    /// Kinds of the only names that are checked, or [`DEFAULT_KINDS`] if empty.
+    pub kinds: Vec<IdentKind>,
+    /// Language of the sources.
+    pub language: Language,
+}
+
+impl Lint {
+    /// Adds the path to the sources to check if it is a file, or the sources of the language in
+    /// it, sorted and skipping hidden entries, if it is a directory.
+    ///
+    /// Files given explicitly are checked even without a known extension.
+    fn find_sources(
+        &self,
+        path: &Path,
+        explicit: bool,
+        sources: &mut Vec<(PathBuf, Language)>,
+    ) -> io::Result<()> {
+        if !fs::metadata(path)?.is_dir() {
+            let language = Language::from_path(&path.to_string_lossy())
+                .filter(|language| language.convention() == self.language.convention());
+            if explicit || language.is_some() {
+                sources.push((path.to_path_buf(), language.unwrap_or(self.language)));
+            }
+            return Ok(());
+        }
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| entry.map(|found| found.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        for entry in entries {
+            let is_hidden = entry
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if !is_hidden && !fs::symlink_metadata(&entry)?.is_symlink() {
+                self.find_sources(&entry, false, sources)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the name following the convention if it breaks it and is checked, or `None`.
+    ///
+    /// Names that can't be converted, like the raw identifiers of Rust, are left alone, and so
+    /// are those that only miss the separators around their digits, like `visit_u64`.
+    fn fix_name(&self, language: Language, kind: IdentKind, name: &str) -> Option<String> {
+        let kinds = if self.kinds.is_empty() {
+            DEFAULT_KINDS
+        } else {
+            &self.kinds
+        };
+        if !kinds.contains(&kind)
+            || !name
+                .chars()
+                .all(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '$'))
+        {
+            return None;
+        }
+        let fixed = language.convention().convert(kind, name);
+        (fixed != name && without_digit_separators(&fixed) != name && !language.is_keyword(&fixed))
+            .then_some(fixed)
+    }
+
+    /// Checks the names of the source file, writes the ones that break the convention, and
+    /// rewrites the file with their fixes if fixing, marking the names that are fixed.
+    ///
+    /// Only the names other files can't see, like locals and private items, are fixed, since
+    /// their uses are all in the file, unless they are used in the interpolations of strings.
+    /// Returns the number of names that break the convention and
+    /// are left unfixed.
+    fn lint_file<W: Write>(
+        &self,
+        path: &Path,
+        language: Language,
+        out: &mut W,
+    ) -> io::Result<usize> {
+        let text = fs::read_to_string(path)?;
+        let mut report = |kind: IdentKind,
+                          name: &str,
+                          position: Option<(usize, usize)>,
+                          fixable: bool|
+         -> io::Result<bool> {
+            let Some(fixed) = self.fix_name(language, kind, name) else {
+                return Ok(false);
+            };
+            let case = language.convention().case_for(kind, name);
+            let location =
+                position.map_or_else(String::new, |(line, column)| format!(":{line}:{column}"));
+            let mark = if self.fix && fixable { ", fixed" } else { "" };
+            writeln!(
+                out,
+                "{}{location}: {kind} {name} -> {fixed} ({case} case{mark})",
+                path.display()
+            )?;
+            Ok(true)
+        };
+        let mut broken: usize = 0;
+        let mut visible: usize = 0;
+        let stem = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('.').next())
+            .unwrap_or_default();
+        if !stem.is_empty() && report(IdentKind::FileName, stem, None, false)? {
+            broken = broken.saturating_add(1);
+            visible = visible.saturating_add(1);
+        }
+        let mut private = HashSet::new();
+        for declaration in code::declarations(language, &text) {
+            let Declaration {
+                kind, name, offset, ..
+            } = declaration;
+            let fixable = declaration.private && !declaration.interpolated;
+            if report(kind, name, Some(position(&text, offset)), fixable)? {
+                broken = broken.saturating_add(1);
+                if fixable {
+                    private.insert((name, kind));
+                } else {
+                    visible = visible.saturating_add(1);
+                }
+            }
+        }
+        if !self.fix {
+            return Ok(broken);
+        }
+        let fixed = code::rewrite(language, &text, |kind, name| {
+            if private.contains(&(name, kind)) {
+                self.fix_name(language, kind, name)
+            } else {
+                None
+            }
+        });
+        if fixed != text {
+            files::replace(path, fixed.as_bytes(), None)?;
+        }
+        Ok(visible)
+    }
+
+    /// Returns the options to check the sources of the language with the default kinds.
+    pub const fn new(language: Language) -> Self {
+        Self {
+            fix: false,
+            kinds: vec![],
+            language,
+        }
+    }
+
+    /// Checks the names of the source files, and of the sources of the language found in the
+    /// directories, recursively, and writes the ones that break the convention with their fix.
+    ///
+    /// Fails with [`Status::CheckFailed`] if any name breaks the convention and isn't fixed.
+    pub fn run<W: Write>(&self, paths: &[String], out: &mut W) -> Result<(), Status> {
+        let mut sources = vec![];
+        for path in paths {
+            self.find_sources(Path::new(path), true, &mut sources)
+                .map_err(|err| io::Error::new(err.kind(), format!("{path}: {err}")))?;
+        }
+        let mut unfixed: usize = 0;
+        for (source, language) in sources {
+            let left = self.lint_file(&source, language, out).map_err(|err| {
+                io::Error::new(err.kind(), format!("{}: {err}", source.display()))
+            })?;
+            unfixed = unfixed.saturating_add(left);
+        }
+        let convention = self.language.convention();
+        match unfixed {
+            0 => Ok(()),
+            1 => Err(Status::CheckFailed(format!(
+                "1 name doesn't follow the {convention} convention"
+            ))),
+            _ => Err(Status::CheckFailed(format!(
+                "{unfixed} names don't follow the {convention} convention"
+            ))),
+        }
+    }
+}
+
+/// Returns the line and column of the byte offset in the text, counting from 1.
+fn position(text: &str, offset: usize) -> (usize, usize) {
+    let before = text.get(..offset).unwrap_or_default();
+    let line_start = before
+        .rfind('\n')
+        .map_or(0, |newline| newline.saturating_add(1));
+    let line = before.matches('\n').count().saturating_add(1);
+    let column = before
+        .get(line_start..)
+        .unwrap_or_default()
+        .chars()
+        .count()
+        .saturating_add(1);
+    (line, column)
+}
+
+/// Removes the separators next to the digits of the name, like `u_64` to `u64`.
+fn without_digit_separators(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut kept = String::with_capacity(name.len());
+    for (index, &ch) in chars.iter().enumerate() {
+        let is_digit_at = |other: Option<usize>| {
+            other
+                .and_then(|position| chars.get(position))
+                .is_some_and(char::is_ascii_digit)
+        };
+        let is_separator = matches!(ch, '_' | '-' | '.');
+        if !(is_separator
+            && (is_digit_at(index.checked_sub(1)) || is_digit_at(index.checked_add(1))))
+        {
+            kept.push(ch);
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lint, position};
+    use crate::bin_helper::lexer::Language;
+    use crate::parser::convention::IdentKind;
+
+    #[test]
+    fn names() {
+        let lint = Lint::new(Language::Go);
+        let fix = |kind, name| lint.fix_name(Language::Go, kind, name);
+        assert_eq!(
+            fix(IdentKind::Function, "get_user"),
+            Some("getUser".to_owned())
+        );
+        assert_eq!(fix(IdentKind::Function, "ServeHTTP"), None);
+        assert_eq!(fix(IdentKind::Function, "parseUrl"), None);
+        assert_eq!(fix(IdentKind::Type, "Base64Encoder"), None);
+        assert_eq!(fix(IdentKind::Local, "user_id"), None);
+        assert_eq!(
+            Lint::new(Language::Rust).fix_name(Language::Rust, IdentKind::Function, "visit_u64"),
+            None
+        );
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(position("fn main() {}", 3), (1, 4));
+        assert_eq!(position("// \u{e9}\nfn main() {}\n", 9), (2, 4));
+    }
+}
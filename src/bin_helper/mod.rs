@@ -1,4 +1,36 @@
+/// Splits the command line arguments into options and values
+pub mod args;
 /// Parses the input arguments
 pub mod cli;
+/// Converts the identifiers of source files, selected by kind
+pub mod code;
+/// Selects the fields of delimited records to convert, like columns of CSV
+pub mod fields;
+/// Rewrites files atomically for in-place editing
+pub mod files;
+/// Converts the keys of `.env`, INI and properties files, keeping everything else
+pub mod ini;
+/// Converts the keys of JSON documents with a streaming parser
+pub mod json;
+/// Tells the names among the keys of configuration files, and converts them
+pub mod keys;
+/// Splits source code into tokens, telling identifiers apart from strings and comments
+pub mod lexer;
+/// Reports the names of source files that break the naming convention of their language
+pub mod lint;
+/// Converts large inputs on several threads, keeping the records in order
+pub mod parallel;
+/// Finds the tokens to convert in free text
+pub mod pattern;
+/// Splits the input into records, like lines or NUL-separated values
+pub mod records;
+/// Renames files and directories to a case
+pub mod rename;
 /// Holds the status to print to the console (errors, help, etc.)
 pub mod status;
+/// Suggests the closest cases to mistyped case names
+pub mod suggest;
+/// Converts the keys of TOML documents, keeping their comments and layout
+pub mod toml;
+/// Converts the keys of YAML documents, keeping their comments and layout
+pub mod yaml;
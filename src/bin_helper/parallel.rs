@@ -0,0 +1,199 @@
+use core::mem;
+use core::num::NonZeroUsize;
+use std::io::{self, Read, Write};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Mutex, PoisonError};
+use std::{panic, thread};
+
+/// Size of the chunks of input handed to the workers, in bytes.
+const CHUNK_SIZE: u64 = 1 << 20;
+
+/// Chunk of complete records, with the number of its first record and where to send its output.
+struct Job {
+    /// Complete records of the input.
+    chunk: Vec<u8>,
+    /// Number of the first record of the chunk, counting from 1.
+    first: usize,
+    /// Where to send the converted chunk.
+    output: Sender<io::Result<Vec<u8>>>,
+}
+
+/// Converts the records of the reader on `jobs` threads, and writes the outputs in order.
+///
+/// The input is split into chunks of complete records, whose length and number are given by
+/// `complete`, that are converted by `convert` with the number of their first record. A limited number of chunks are in flight at
+/// once, so the memory use doesn't depend on the size of the input. The outputs written before
+/// an error are those of the chunks before the failing one.
+pub fn convert<R, W, Complete, Convert>(
+    jobs: NonZeroUsize,
+    reader: R,
+    complete: &Complete,
+    out: &mut W,
+    convert: &Convert,
+) -> io::Result<()>
+where
+    R: Read + Send,
+    W: Write,
+    Complete: Fn(&[u8]) -> (usize, usize) + Sync,
+    Convert: Fn(usize, &[u8]) -> io::Result<Vec<u8>> + Sync,
+{
+    let (job_sender, receiver) = mpsc::sync_channel::<Job>(jobs.get());
+    let (order_sender, order_receiver) = mpsc::sync_channel(jobs.get().saturating_mul(2));
+    let job_receiver = Mutex::new(receiver);
+    thread::scope(|scope| {
+        let splitter = scope.spawn(move || split(reader, complete, &job_sender, &order_sender));
+        for _ in 0..jobs.get() {
+            scope.spawn(|| work(&job_receiver, convert));
+        }
+        let written = write_in_order(order_receiver, out);
+        let split = splitter
+            .join()
+            .unwrap_or_else(|err| panic::resume_unwind(err));
+        written.and(split)
+    })
+}
+
+/// Reads the chunks of complete records, and sends them to the workers, with the receivers of
+/// their outputs in order.
+///
+/// Stops early without error if the outputs aren't written anymore.
+fn split<R: Read, Complete: Fn(&[u8]) -> (usize, usize)>(
+    mut reader: R,
+    complete: &Complete,
+    jobs: &SyncSender<Job>,
+    order: &SyncSender<Receiver<io::Result<Vec<u8>>>>,
+) -> io::Result<()> {
+    let mut first: usize = 1;
+    let mut buffer = vec![];
+    loop {
+        let read = reader.by_ref().take(CHUNK_SIZE).read_to_end(&mut buffer)?;
+        let (length, count) = complete(&buffer);
+        let chunk = if read == 0 {
+            if buffer.is_empty() {
+                return Ok(());
+            }
+            buffer.split_off(0)
+        } else if length == 0 {
+            continue;
+        } else {
+            let rest = buffer.split_off(length);
+            mem::replace(&mut buffer, rest)
+        };
+        let (output, receiver) = mpsc::channel();
+        if order.send(receiver).is_err()
+            || jobs
+                .send(Job {
+                    chunk,
+                    first,
+                    output,
+                })
+                .is_err()
+        {
+            return Ok(());
+        }
+        first = first.saturating_add(count);
+    }
+}
+
+/// Converts the chunks received from the splitter until there are none left.
+///
+/// The chunks are still drained once their outputs aren't written anymore, so that the splitter
+/// is never blocked.
+fn work<Convert: Fn(usize, &[u8]) -> io::Result<Vec<u8>>>(
+    jobs: &Mutex<Receiver<Job>>,
+    convert: &Convert,
+) {
+    loop {
+        let received = jobs.lock().unwrap_or_else(PoisonError::into_inner).recv();
+        let Ok(job) = received else {
+            return;
+        };
+        let _unwritten = job.output.send(convert(job.first, &job.chunk));
+    }
+}
+
+/// Writes the outputs of the chunks in the order they were read, until the first error.
+fn write_in_order<W: Write>(
+    order: Receiver<Receiver<io::Result<Vec<u8>>>>,
+    out: &mut W,
+) -> io::Result<()> {
+    for output in order {
+        let Ok(converted) = output.recv() else {
+            return Ok(());
+        };
+        out.write_all(&converted?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "tests")]
+
+    use core::num::NonZeroUsize;
+    use std::io;
+
+    use super::convert;
+    use crate::bin_helper::records;
+
+    /// Returns the length and number of the complete lines.
+    fn complete(bytes: &[u8]) -> (usize, usize) {
+        records::complete(bytes, b"\n", false)
+    }
+
+    /// Replaces every line of the input with its number, on 4 threads.
+    fn numbers(input: &[u8]) -> io::Result<Vec<u8>> {
+        let mut out = vec![];
+        convert(
+            NonZeroUsize::new(4).unwrap(),
+            input,
+            &complete,
+            &mut out,
+            &|first, chunk| {
+                let lines = chunk.split(|byte| *byte == b'\n').count().saturating_sub(1);
+                Ok(
+                    (first..first.saturating_add(lines)).fold(vec![], |mut numbers, number| {
+                        numbers.extend(format!("{number}\n").bytes());
+                        numbers
+                    }),
+                )
+            },
+        )?;
+        Ok(out)
+    }
+
+    #[test]
+    fn order() {
+        let input = "some line\n".repeat(500_000);
+        let expected = (1..=500_000)
+            .map(|number: u32| number.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            numbers(input.as_bytes()).unwrap(),
+            format!("{expected}\n").into_bytes()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let input = "some line\n".repeat(500_000);
+        let mut out = vec![];
+        let err = convert(
+            NonZeroUsize::new(4).unwrap(),
+            input.as_bytes(),
+            &complete,
+            &mut out,
+            &|first, chunk| {
+                if first == 1 {
+                    Ok(chunk.to_vec())
+                } else {
+                    Err(io::Error::other(format!("line {first}")))
+                }
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "line 104858");
+        assert_eq!(out, "some line\n".repeat(104_857).into_bytes());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::parser::case::Case;
+
+/// Pattern of the tokens to convert in free text, the rest of the text being left unchanged.
+///
+/// The tokens are the runs of letters, digits and underscores.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Pattern {
+    /// Tokens that match the glob, where `*` matches any characters and `?` a single one.
+    Glob(Vec<char>),
+    /// Tokens made of several words, like `getUserName` or `user_id`, but not `Hello` or `mp3`.
+    Identifiers,
+}
+
+impl Pattern {
+    /// Returns `true` iff the token matches the pattern.
+    pub fn matches(&self, token: &str) -> bool {
+        match self {
+            Self::Glob(glob) => matches_glob(glob, &token.chars().collect::<Vec<_>>()),
+            Self::Identifiers => {
+                Case::Snake
+                    .caseify(token)
+                    .split('_')
+                    .filter(|word| word.chars().any(char::is_alphabetic))
+                    .count()
+                    > 1
+            }
+        }
+    }
+
+    /// Converts the tokens of the text that match the pattern with `convert`, and returns the
+    /// text with everything else unchanged.
+    pub fn replace<Convert: Fn(&str) -> String>(&self, text: &str, convert: Convert) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find(is_token_char) {
+            let (before, from_token) = rest.split_at(start);
+            let end = from_token
+                .find(|ch: char| !is_token_char(ch))
+                .unwrap_or(from_token.len());
+            let (token, after) = from_token.split_at(end);
+            output.push_str(before);
+            if self.matches(token) {
+                output.push_str(&convert(token));
+            } else {
+                output.push_str(token);
+            }
+            rest = after;
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
+/// Returns `true` iff the character can be part of a token.
+fn is_token_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Returns `true` iff the characters match the glob.
+///
+/// On a mismatch, the last `*` is retried on one more character, which is enough as every
+/// previous `*` would match a prefix of what it can match.
+fn matches_glob(glob: &[char], chars: &[char]) -> bool {
+    let mut glob_index: usize = 0;
+    let mut char_index: usize = 0;
+    let mut star = None;
+    while char_index < chars.len() {
+        match glob.get(glob_index) {
+            Some('*') => {
+                star = Some((glob_index, char_index));
+                glob_index = glob_index.saturating_add(1);
+            }
+            Some(&expected) if expected == '?' || chars.get(char_index) == Some(&expected) => {
+                glob_index = glob_index.saturating_add(1);
+                char_index = char_index.saturating_add(1);
+            }
+            Some(_) | None => {
+                let Some((star_glob, star_char)) = star else {
+                    return false;
+                };
+                star = Some((star_glob, star_char.saturating_add(1)));
+                glob_index = star_glob.saturating_add(1);
+                char_index = star_char.saturating_add(1);
+            }
+        }
+    }
+    glob.get(glob_index..)
+        .is_some_and(|rest| rest.iter().all(|ch| *ch == '*'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pattern, matches_glob};
+
+    fn glob(glob: &str, value: &str) -> bool {
+        matches_glob(
+            &glob.chars().collect::<Vec<_>>(),
+            &value.chars().collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn globs() {
+        assert!(glob("get*", "getUserName"));
+        assert!(glob("*Name", "getUserName"));
+        assert!(glob("*User*", "getUserName"));
+        assert!(glob("g?t*e", "getUserName"));
+        assert!(glob("*", ""));
+        assert!(!glob("get*", "setUserName"));
+        assert!(!glob("*Id", "getUserName"));
+        assert!(!glob("?", ""));
+        assert!(!glob("a*b*c", "abab"));
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(
+            Pattern::Identifiers.replace(
+                "Call getUserName() on user_id, not on HTTPServer! Hello mp3.",
+                str::to_uppercase
+            ),
+            "Call GETUSERNAME() on USER_ID, not on HTTPSERVER! Hello mp3."
+        );
+    }
+
+    #[test]
+    fn replace() {
+        assert_eq!(
+            Pattern::Glob("get*".chars().collect())
+                .replace("  getUserName,setUserName;get  ", str::to_uppercase),
+            "  GETUSERNAME,setUserName;GET  "
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use core::str::{self, FromStr};
+use std::io::{self, BufRead, Write};
+
+/// Separator of the records read from stdin or files, and written to stdout.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Records ended by the given string, like `\0` for `-0`.
+    Custom(String),
+    /// Lines, ended by `\n` or `\r\n`, whichever is found in the input.
+    #[default]
+    Lines,
+}
+
+impl Delimiter {
+    /// Returns the bytes that end a record, which can't be empty.
+    pub fn end(&self) -> io::Result<&[u8]> {
+        match self {
+            Self::Custom(delimiter) if delimiter.is_empty() => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the delimiter can't be empty",
+            )),
+            Self::Custom(delimiter) => Ok(delimiter.as_bytes()),
+            Self::Lines => Ok(b"\n"),
+        }
+    }
+
+    /// Returns the terminator written after the values that weren't read with one, like the
+    /// values given as arguments.
+    pub fn output(&self) -> &str {
+        match self {
+            Self::Custom(delimiter) => delimiter,
+            Self::Lines => "\n",
+        }
+    }
+
+    /// Returns the name of a record in messages, like `line`.
+    pub const fn record_name(&self) -> &'static str {
+        match self {
+            Self::Custom(_) => "record",
+            Self::Lines => "line",
+        }
+    }
+}
+
+/// How to handle the records that aren't valid UTF-8.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Invalid {
+    /// Fail on the first invalid record.
+    #[default]
+    Error,
+    /// Replace the invalid bytes with `U+FFFD REPLACEMENT CHARACTER`.
+    Lossy,
+    /// Write the invalid records unchanged.
+    Pass,
+}
+
+impl FromStr for Invalid {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode {
+            "error" => Ok(Self::Error),
+            "lossy" => Ok(Self::Lossy),
+            "pass" => Ok(Self::Pass),
+            _ => Err(format!(
+                "Invalid value `{mode}` for `--invalid`, expected `error`, `lossy` or `pass`"
+            )),
+        }
+    }
+}
+
+/// Record read from the input, with the terminator that ended it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Record<'delimiter> {
+    /// Terminator of the record, empty for a last record without one.
+    pub terminator: &'delimiter str,
+    /// Contents of the record, without its terminator.
+    pub value: Vec<u8>,
+}
+
+impl Record<'_> {
+    /// Returns the contents of the record as text, with the invalid UTF-8 handled as asked.
+    ///
+    /// Fails if the record isn't valid UTF-8 and the invalid records are errors.
+    pub fn text(&self, invalid: Invalid) -> io::Result<Text<'_>> {
+        match (str::from_utf8(&self.value), invalid) {
+            (Ok(text), _) => Ok(Text::Valid(text)),
+            (Err(err), Invalid::Error) => Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+            (Err(_), Invalid::Lossy) => Ok(Text::Replaced(
+                String::from_utf8_lossy(&self.value).into_owned(),
+            )),
+            (Err(_), Invalid::Pass) => Ok(Text::Invalid),
+        }
+    }
+}
+
+/// Contents of a [`Record`] as text.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Text<'record> {
+    /// The record isn't valid UTF-8, and is passed through unchanged.
+    Invalid,
+    /// The record isn't valid UTF-8, and its invalid bytes were replaced.
+    Replaced(String),
+    /// The record is valid UTF-8.
+    Valid(&'record str),
+}
+
+/// Writer that remembers the last bytes written, to tell if the output ends with a terminator.
+pub struct Ending<'end, W> {
+    /// Bytes that end a record.
+    end: &'end [u8],
+    /// Last bytes written, as many as those of `end` at most.
+    tail: Vec<u8>,
+    /// Writer to write to.
+    writer: W,
+}
+
+impl<'end, W: Write> Ending<'end, W> {
+    /// Returns `true` iff something was written, and it doesn't end with `end`.
+    pub fn is_unterminated(&self) -> bool {
+        !self.tail.is_empty() && !self.tail.ends_with(self.end)
+    }
+
+    /// Writes to the writer, remembering if the output ends with `end`.
+    pub const fn new(end: &'end [u8], writer: W) -> Self {
+        Self {
+            end,
+            tail: vec![],
+            writer,
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided methods are based on `write` and `flush`"
+)]
+impl<W: Write> Write for Ending<'_, W> {
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.writer.write(buf)?;
+        self.tail
+            .extend_from_slice(buf.get(..written).unwrap_or_default());
+        let extra = self.tail.len().saturating_sub(self.end.len());
+        self.tail.drain(..extra);
+        Ok(written)
+    }
+}
+
+/// Iterator over the records of a reader, separated by a [`Delimiter`].
+pub struct Records<'delimiter, R> {
+    /// Separator of the records.
+    delimiter: &'delimiter Delimiter,
+    /// Whether the records don't end inside quotes, like the fields of CSV.
+    quoted: bool,
+    /// Reader to read the records from.
+    reader: R,
+}
+
+impl<'delimiter, R: BufRead> Records<'delimiter, R> {
+    /// Reads the records of the reader, that don't end inside quotes with `quoted`.
+    pub const fn new(reader: R, delimiter: &'delimiter Delimiter, quoted: bool) -> Self {
+        Self {
+            delimiter,
+            quoted,
+            reader,
+        }
+    }
+
+    /// Reads the next record, or returns `None` at the end of the input.
+    fn read_record(&mut self) -> io::Result<Option<Record<'delimiter>>> {
+        let end = self.delimiter.end()?;
+        let Some(last) = end.last().copied() else {
+            return Ok(None);
+        };
+        let mut value = vec![];
+        loop {
+            let read = self.reader.read_until(last, &mut value)?;
+            let ended = value.ends_with(end) && !(self.quoted && is_quote_open(&value));
+            if read == 0 || ended || value.last() != Some(&last) {
+                break;
+            }
+        }
+        if value.is_empty() {
+            return Ok(None);
+        }
+        let terminator = match self.delimiter {
+            Delimiter::Lines if value.ends_with(b"\r\n") => "\r\n",
+            Delimiter::Lines if value.ends_with(b"\n") => "\n",
+            Delimiter::Custom(delimiter) if value.ends_with(delimiter.as_bytes()) => delimiter,
+            Delimiter::Lines | Delimiter::Custom(_) => "",
+        };
+        value.truncate(value.len().saturating_sub(terminator.len()));
+        Ok(Some(Record { terminator, value }))
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided methods are based on `next`"
+)]
+impl<'delimiter, R: BufRead> Iterator for Records<'delimiter, R> {
+    type Item = io::Result<Record<'delimiter>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// Returns the length of the complete records at the start of the bytes, that end with `end`,
+/// and their number.
+///
+/// With `quoted`, the records don't end inside quotes, like the fields of CSV.
+pub fn complete(bytes: &[u8], end: &[u8], quoted: bool) -> (usize, usize) {
+    let mut length: usize = 0;
+    let mut searched: usize = 0;
+    let mut count: usize = 0;
+    while let Some(found) = bytes
+        .get(searched..)
+        .and_then(|rest| rest.windows(end.len()).position(|window| window == end))
+    {
+        searched = searched.saturating_add(found).saturating_add(end.len());
+        if !(quoted && bytes.get(length..searched).is_some_and(is_quote_open)) {
+            length = searched;
+            count = count.saturating_add(1);
+        }
+    }
+    (length, count)
+}
+
+/// Returns `true` iff the bytes have an odd number of quotes, so the last quoted field isn't
+/// closed.
+fn is_quote_open(bytes: &[u8]) -> bool {
+    bytes
+        .iter()
+        .fold(false, |open, byte| open ^ (*byte == b'"'))
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "tests")]
+
+    use std::io::Write as _;
+
+    use super::{Delimiter, Ending, Invalid, Record, Records, Text, complete};
+
+    fn records(input: &str, delimiter: &Delimiter) -> Vec<(String, String)> {
+        Records::new(input.as_bytes(), delimiter, false)
+            .map(|record| {
+                let read = record.unwrap();
+                let text = match read.text(Invalid::Error).unwrap() {
+                    Text::Valid(valid) => valid.to_owned(),
+                    other @ (Text::Invalid | Text::Replaced(_)) => format!("{other:?}"),
+                };
+                (text, read.terminator.to_owned())
+            })
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+        expected
+            .iter()
+            .map(|(value, terminator)| ((*value).to_owned(), (*terminator).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            records("a\r\nb\nc", &Delimiter::Lines),
+            pairs(&[("a", "\r\n"), ("b", "\n"), ("c", "")])
+        );
+        assert_eq!(
+            records("a\n\n", &Delimiter::Lines),
+            pairs(&[("a", "\n"), ("", "\n")])
+        );
+        assert_eq!(records("", &Delimiter::Lines), pairs(&[]));
+    }
+
+    #[test]
+    fn custom() {
+        let null = Delimiter::Custom("\0".to_owned());
+        assert_eq!(
+            records("a\nb\0c\0", &null),
+            pairs(&[("a\nb", "\0"), ("c", "\0")])
+        );
+        let comma = Delimiter::Custom(", ".to_owned());
+        assert_eq!(
+            records("a,b, c, ", &comma),
+            pairs(&[("a,b", ", "), ("c", ", ")])
+        );
+        assert_eq!(records("a ,", &comma), pairs(&[("a ,", "")]));
+    }
+
+    #[test]
+    fn invalid() {
+        let record = Record {
+            terminator: "\n",
+            value: b"caf\xe9".to_vec(),
+        };
+        record.text(Invalid::Error).unwrap_err();
+        assert_eq!(
+            record.text(Invalid::Lossy).unwrap(),
+            Text::Replaced("caf\u{fffd}".to_owned())
+        );
+        assert_eq!(record.text(Invalid::Pass).unwrap(), Text::Invalid);
+    }
+
+    #[test]
+    fn ending() {
+        let mut written = vec![];
+        let mut ending = Ending::new(b", ", &mut written);
+        assert!(!ending.is_unterminated());
+        ending.write_all(b"a,").unwrap();
+        assert!(ending.is_unterminated());
+        ending.write_all(b" ").unwrap();
+        assert!(!ending.is_unterminated());
+        ending.write_all(b"b").unwrap();
+        assert!(ending.is_unterminated());
+        assert_eq!(written, b"a, b");
+    }
+
+    #[test]
+    fn complete_records() {
+        assert_eq!(complete(b"a\nb\r\nc", b"\n", false), (5, 2));
+        assert_eq!(complete(b"a, b, c, ", b", ", false), (9, 3));
+        assert_eq!(complete(b"aaa", b"aa", false), (2, 1));
+        assert_eq!(complete(b"abc", b"\n", false), (0, 0));
+        assert_eq!(complete(b"a,\"b\nc\"\nd,\"e\n", b"\n", true), (8, 1));
+    }
+}
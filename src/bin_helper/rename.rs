@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::bin_helper::files;
+use crate::bin_helper::status::Status;
+use crate::parser::case::Case;
+
+/// Renaming of a file or directory.
+#[derive(Debug)]
+struct Renaming {
+    /// Current path of the file or directory.
+    from: PathBuf,
+    /// Path of the file or directory once renamed.
+    to: PathBuf,
+}
+
+impl Renaming {
+    /// Returns `true` iff the renaming only changes the case of the letters, so the file systems
+    /// that ignore case see it as a no-op.
+    fn is_case_only(&self) -> bool {
+        self.from.to_string_lossy().to_lowercase() == self.to.to_string_lossy().to_lowercase()
+    }
+
+    /// Returns `true` iff a file other than the renamed one already exists at the target path.
+    ///
+    /// On file systems that ignore case, the target of a case-only renaming exists as the renamed
+    /// file itself, so it is only taken if the directory lists the target name exactly.
+    fn is_taken(&self) -> io::Result<bool> {
+        if fs::symlink_metadata(&self.to).is_err() {
+            return Ok(false);
+        }
+        if !self.is_case_only() {
+            return Ok(true);
+        }
+        let parent = self
+            .to
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let name = self.to.file_name();
+        for entry in fs::read_dir(parent)? {
+            if Some(entry?.file_name().as_os_str()) == name {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Renames the file or directory, through a temporary name for case-only renamings.
+    fn perform(&self) -> io::Result<()> {
+        if self.is_case_only() {
+            let temp = files::temp_sibling(&self.from)?;
+            fs::rename(&self.from, &temp)?;
+            fs::rename(&temp, &self.to)
+        } else {
+            fs::rename(&self.from, &self.to)
+        }
+    }
+}
+
+/// Renames the files and directories to the given case, and writes the renamings to the output.
+///
+/// The contents of the directories are renamed first if `recursive` is set. Nothing is renamed
+/// if two paths would be renamed to the same target, or if a target already exists, which fails
+/// with [`Status::CheckFailed`]. Nothing is renamed either with `dry_run`, but the renamings are
+/// still written.
+pub fn run<W: Write>(
+    paths: &[String],
+    case: Case,
+    recursive: bool,
+    dry_run: bool,
+    out: &mut W,
+) -> Result<(), Status> {
+    let mut plan = vec![];
+    let mut seen = HashSet::new();
+    for path in paths {
+        plan_renamings(Path::new(path), case, recursive, &mut plan, &mut seen)
+            .map_err(|err| io::Error::new(err.kind(), format!("{path}: {err}")))?;
+    }
+
+    let collisions = collisions(&plan)?;
+    if !collisions.is_empty() {
+        return Err(Status::CheckFailed(format!(
+            "Nothing was renamed because of collisions:\n{}",
+            collisions.join("\n")
+        )));
+    }
+
+    for renaming in &plan {
+        writeln!(
+            out,
+            "{} -> {}",
+            renaming.from.display(),
+            renaming.to.display()
+        )?;
+        if !dry_run {
+            renaming.perform().map_err(|err| {
+                io::Error::new(err.kind(), format!("{}: {err}", renaming.from.display()))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the new name of a file or directory converted to the given case.
+///
+/// The leading dots of hidden files are kept, and so are all the extensions of files, like
+/// `.tar.gz`. Names that would become empty are kept unchanged.
+fn case_name(name: &str, case: Case, is_dir: bool) -> String {
+    let stem_start = name.find(|ch| ch != '.').unwrap_or(name.len());
+    let (dots, rest) = name.split_at(stem_start);
+    let (stem, extension) = match rest.split_once('.') {
+        Some((stem, extension)) if !is_dir && !stem.is_empty() => (stem, format!(".{extension}")),
+        _ => (rest, String::new()),
+    };
+    let converted = case.caseify(stem);
+    if converted.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{dots}{converted}{extension}")
+    }
+}
+
+/// Returns the descriptions of the renamings that can't be performed, because they have the
+/// same target (ignoring case) or their target already exists.
+fn collisions(plan: &[Renaming]) -> io::Result<Vec<String>> {
+    let mut targets: HashMap<String, Vec<&Renaming>> = HashMap::new();
+    for renaming in plan {
+        targets
+            .entry(renaming.to.to_string_lossy().to_lowercase())
+            .or_default()
+            .push(renaming);
+    }
+    let mut collisions = vec![];
+    for renaming in plan {
+        let Some(sources) = targets.get(&renaming.to.to_string_lossy().to_lowercase()) else {
+            continue;
+        };
+        if sources.len() > 1
+            && sources
+                .first()
+                .is_some_and(|first| first.from == renaming.from)
+        {
+            let names = sources
+                .iter()
+                .map(|source| source.from.display().to_string())
+                .collect::<Vec<_>>();
+            collisions.push(format!(
+                "  {} would all be renamed to {}",
+                names.join(", "),
+                renaming.to.display()
+            ));
+        }
+        if renaming.is_taken()? {
+            collisions.push(format!("  {} already exists", renaming.to.display()));
+        }
+    }
+    Ok(collisions)
+}
+
+/// Adds the renaming of the path to the plan, after the renamings of its contents if `recursive`
+/// is set, so that the paths stay valid until they are renamed.
+fn plan_renamings(
+    path: &Path,
+    case: Case,
+    recursive: bool,
+    plan: &mut Vec<Renaming>,
+    seen: &mut HashSet<PathBuf>,
+) -> io::Result<()> {
+    let is_dir = fs::symlink_metadata(path)?.is_dir();
+    if recursive && is_dir {
+        let mut children = fs::read_dir(path)?
+            .map(|entry| entry.map(|child| child.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        children.sort();
+        for child in children {
+            plan_renamings(&child, case, recursive, plan, seen)?;
+        }
+    }
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return Ok(());
+    };
+    let new_name = case_name(name, case, is_dir);
+    if new_name != name && seen.insert(path.to_path_buf()) {
+        plan.push(Renaming {
+            from: path.to_path_buf(),
+            to: path.with_file_name(new_name),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::case_name;
+    use crate::parser::case::Case;
+
+    #[test]
+    fn names() {
+        assert_eq!(case_name("My File.txt", Case::Snake, false), "my_file.txt");
+        assert_eq!(case_name("My File.txt", Case::Dot, false), "my.file.txt");
+        assert_eq!(
+            case_name("myArchive.tar.gz", Case::Kebab, false),
+            "my-archive.tar.gz"
+        );
+        assert_eq!(
+            case_name("fooBar.test.js", Case::Kebab, false),
+            "foo-bar.test.js"
+        );
+        assert_eq!(case_name("My.Dir", Case::Kebab, true), "my-dir");
+        assert_eq!(case_name(".hiddenFile", Case::Snake, false), ".hidden_file");
+        assert_eq!(case_name(".env.Local", Case::Snake, false), ".env.Local");
+        assert_eq!(case_name("README", Case::Pascal, false), "Readme");
+        assert_eq!(case_name("___.md", Case::Snake, false), "___.md");
+    }
+}
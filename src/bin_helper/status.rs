@@ -1,6 +1,9 @@
-use std::env;
+use std::{env, io};
 
+use crate::bin_helper::cli::SHORT_OPTIONS;
 use crate::parser::case::Case;
+use crate::parser::case_cycle::CaseCycle;
+use crate::parser::caseify::Caseify as _;
 
 /// Maximum length of the case names.
 const MAX_CASE_NAME_LEN: usize = {
@@ -18,50 +21,222 @@ const MAX_CASE_NAME_LEN: usize = {
     max
 };
 
-/// Status of the command, that determines what to print to the terminal.
+/// Status of the command, that determines what to print to the terminal and the exit code.
 #[derive(Debug)]
 pub enum Status {
-    /// An error occurred.
-    Error(String),
+    /// Some values aren't in the expected case.
+    CheckFailed(String),
     /// Display the help message
     Help,
+    /// The case name or option is invalid, with the closest cases to suggest instead.
+    InvalidCase(String, Vec<Case>),
+    /// The input couldn't be read as text.
+    InvalidInput(String),
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// The command line arguments are invalid.
+    Usage(String),
 }
 
 impl Status {
+    /// Returns the exit code of the process, following the `sysexits.h` conventions for errors.
+    ///
+    /// | Status           | Code |
+    /// |------------------|------|
+    /// | [`Help`]         | 0    |
+    /// | [`CheckFailed`]  | 1    |
+    /// | [`InvalidCase`]  | 64   |
+    /// | [`Usage`]        | 64   |
+    /// | [`InvalidInput`] | 65   |
+    /// | [`Io`]           | 74   |
+    ///
+    /// [`Help`]: Self::Help
+    /// [`CheckFailed`]: Self::CheckFailed
+    /// [`InvalidCase`]: Self::InvalidCase
+    /// [`Usage`]: Self::Usage
+    /// [`InvalidInput`]: Self::InvalidInput
+    /// [`Io`]: Self::Io
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::Help => 0,
+            Self::CheckFailed(_) => 1,
+            Self::InvalidCase(..) | Self::Usage(_) => 64,
+            Self::InvalidInput(_) => 65,
+            Self::Io(_) => 74,
+        }
+    }
+
     /// Displays the help message to the screen with a given error message.
     pub fn to_string(&self, arg0: &str) -> String {
         let (red, green, cyan, magenta, nil) = get_colours();
-        let usage = format!("{magenta}Usage: {arg0} <{green}case{magenta}> [value] [--help]{nil}");
+        let usage =
+            format!("{magenta}Usage: {arg0} [options] <{green}case{magenta}> [values...]{nil}");
 
         match self {
-            Self::Error(err) => {
+            Self::CheckFailed(err) => format!("{red}{err}{nil}"),
+            Self::InvalidCase(err, suggestions) => {
+                let hint = match suggestions.as_slice() {
+                    [] => String::new(),
+                    [case] => format!(", did you mean `{case}`?"),
+                    _ => ", did you mean one of these?".to_owned(),
+                };
+                let table = if suggestions.is_empty() {
+                    String::new()
+                } else {
+                    format!("\n\n{}", help_table(suggestions, green, nil))
+                };
+                format!("{red}Failed to run {arg0}: {err}{hint}{nil}{table}\n\n{usage}")
+            }
+            Self::InvalidInput(err) => format!("{red}Failed to run {arg0}: {err}{nil}"),
+            Self::Io(err) => format!("{red}Failed to run {arg0}: {err}{nil}"),
+            Self::Usage(err) => {
                 format!("{red}Failed to run {arg0}: {err}{nil}\n\n{usage}")
             }
-            Self::Help => {
-                format!(
-                    "\
+            Self::Help => help(&usage, green, cyan, magenta, nil),
+        }
+    }
+}
+
+impl From<io::Error> for Status {
+    fn from(err: io::Error) -> Self {
+        if err.kind() == io::ErrorKind::InvalidData {
+            Self::InvalidInput(err.to_string())
+        } else {
+            Self::Io(err)
+        }
+    }
+}
+
+/// Returns the short and long options of a case, like `-s, --snake`.
+fn case_options(case: Case) -> String {
+    let long = case.to_string().to_kebab_case();
+    SHORT_OPTIONS
+        .iter()
+        .find(|(_, other)| *other == case)
+        .map_or_else(
+            || format!("    --{long}"),
+            |(short, _)| format!("-{short}, --{long}"),
+        )
+}
+
+/// Returns the help message, with the usage line first.
+fn help(usage: &str, green: &str, cyan: &str, magenta: &str, nil: &str) -> String {
+    format!(
+        "\
 {usage}
-{magenta}Omit `value` to read from stdin (e.g. for pipes){nil}
+{magenta}Omit `values` to read from stdin (e.g. for pipes), keeping the line endings{nil}
 
-Possible {green}case{nil} values:
+Possible {green}case{nil} values, also accepted in any case or as an alias (e.g. `snake_case`):
 {}
 
+Options
+  {green}-<short>{nil}, {green}--<case>{nil}  Case to convert to, instead of the first argument
+  --case <{green}case{nil}>       Case to convert to, by name or alias
+  -j, --join          Join the values with spaces into a single phrase
+  -f, --files         Convert the lines of the files given as values
+  -i, --in-place      Rewrite the files in place (`--in-place=<suffix>` keeps a backup)
+  -0, --null          Separate the records of stdin and files with NUL instead of lines
+  --delimiter <str>   Separate the records of stdin and files with the given string
+  --invalid <mode>    Handle invalid UTF-8 input: `error` (default), `lossy` or `pass`
+  --jobs <n>          Convert the records of stdin and files on n threads, in order
+  --fields <list>     Only convert the given fields of the records, like `1,3` (or `--field`)
+  --separator <str>   Separate the fields with the given string instead of tabs
+  --csv               Read the fields as CSV, with quotes and commas by default
+  --header-only       Only convert the fields of the first record
+  --match [<glob>]    Only convert identifiers in text, or the words matching a glob
+  --depth <n>         Only convert the keys of the n outermost levels of JSON objects
+  --include <glob>    Only convert the JSON keys that match the glob, can be repeated
+  --exclude <glob>    Don't convert the JSON keys that match the glob, can be repeated
+  --values            Convert the JSON string values too
+  --pretty            Indent the JSON output by two spaces
+  --compact           Write the JSON output without whitespace, a document per line
+  --lang <name>       Language of the sources: `c`, `go`, `js`, `py`, `rust` or `ts`
+  --kind <list>       Only convert the given kinds of identifiers, like `local,field`
+  --fix               Rename the private names that break the convention with `lint`
+  --porcelain         Print the results of `detect` as `<cases>\\t<value>` lines
+  -r, --recursive     Rename the contents of the directories too
+  -n, --dry-run       Print the renames without performing them
+  -h, --help          Print this help message
+  --                  Treat the following arguments as values, even if they start with `-`
+
+Use {green}cycle{nil} as {green}case{nil} to convert to the next case of the cycle:
+  {}
+
+Use {green}detect{nil} as {green}case{nil} to print the cases the values are already in.
+Use {green}check{nil} <{green}case{nil}> to report the values that aren't in {green}case{nil}, and fail if there are any.
+Use {green}rename{nil} <{green}case{nil}> to rename the files and directories given as values, keeping all their extensions.
+Use {green}json{nil} <{green}case{nil}> to convert the keys of the JSON documents of the files given as values, or of stdin.
+Use {green}yaml{nil} or {green}toml{nil} <{green}case{nil}> to convert the keys of the files given as values, or of stdin, keeping comments.
+Use {green}env{nil} or {green}ini{nil} <{green}case{nil}> to do the same for .env, INI and properties files, and warn about colliding keys.
+Use {green}code{nil} <{green}case{nil}> to convert the identifiers declared in the source files given as values, keeping strings and comments.
+Use {green}convention{nil} <{green}language{nil}> <{green}kind{nil}> to convert the values to the case of that kind of identifiers in the language, like `rust type`.
+Use {green}lint{nil} <{green}language{nil}> to report the names declared in the files and directories given as values that break the convention of the language.
+
 Examples
 {cyan}$ caseify Camel \"Hello World\"{nil}
 # Output: helloWorld
 {cyan}$ echo \"hello World\" | caseify Constant{nil}
-# Output: HELLO_WORLD",
-                    Case::HELP
-                        .iter()
-                        .map(|(name, example)| {
-                            format!("  {green}{name:<MAX_CASE_NAME_LEN$}{nil}  {example}")
-                        })
-                        .collect::<Vec<_>>()
-                        .join("\n")
-                )
-            }
-        }
-    }
+# Output: HELLO_WORLD
+{cyan}$ caseify \"SomeVariableName\" --snake{nil}
+# Output: some_variable_name
+{cyan}$ caseify Snake hello world --join{nil}
+# Output: hello_world
+{cyan}$ caseify cycle \"hello_world\"{nil}
+# Output: helloWorld
+{cyan}$ caseify detect \"foo_bar\"{nil}
+# Output: foo_bar: Snake
+{cyan}$ caseify check Snake \"foo_bar\" \"fooBar\"{nil}
+# Output: <args>:2: fooBar -> foo_bar
+{cyan}$ caseify rename Kebab -r \"My Photos\"{nil}
+# Output: My Photos -> my-photos
+{cyan}$ echo '{{\"userName\": 1}}' | caseify json Snake{nil}
+# Output: {{\"user_name\": 1}}
+{cyan}$ echo \"apiVersion: v1\" | caseify yaml Snake{nil}
+# Output: api_version: v1
+{cyan}$ echo \"database.url=x\" | caseify env Constant{nil}
+# Output: DATABASE_URL=x
+{cyan}$ echo \"let user_id = 1;\" | caseify code Camel --lang rust{nil}
+# Output: let userId = 1;
+{cyan}$ caseify convention python constant maxSize{nil}
+# Output: MAX_SIZE
+{cyan}$ caseify lint rust src{nil}
+# Output: src/lib.rs:3:4: function getName -> get_name (Snake case)
+
+Exit status
+  0   Success
+  1   Check failed
+  64  Usage error
+  65  Invalid input (e.g. invalid UTF-8)
+  74  IO error",
+        help_table(Case::ALL, green, nil),
+        CaseCycle::default()
+            .order()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    )
+}
+
+/// Returns the table of the given cases, with their options and an example.
+fn help_table(cases: &[Case], green: &str, nil: &str) -> String {
+    let rows = Case::ALL
+        .iter()
+        .zip(Case::HELP)
+        .filter(|(case, _)| cases.contains(case))
+        .map(|(case, help)| (help, case_options(*case)))
+        .collect::<Vec<_>>();
+    let width = rows
+        .iter()
+        .map(|(_, option)| option.len())
+        .max()
+        .unwrap_or_default();
+    rows.iter()
+        .map(|((name, example), option)| {
+            format!("  {green}{name:<MAX_CASE_NAME_LEN$}{nil}  {option:<width$}  {example}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 /// Returns `true` iff the terminal supports ANSI escope codes.
@@ -0,0 +1,84 @@
+use core::iter;
+
+use crate::parser::case::{Case, normalise};
+
+/// Minimum length of a mistyped name to suggest the cases it is a prefix of (e.g. `sent`).
+const MIN_PREFIX_LEN: usize = 3;
+
+/// Returns the cases whose name or aliases are the closest to a mistyped case name.
+///
+/// A case is close if its name or one of its aliases starts with the mistyped name, or is at an
+/// edit distance of at most a third of the length of the mistyped name, plus one. Only the cases
+/// at the smallest distance are returned, in the order of [`Case::ALL`].
+pub fn closest_cases(name: &str) -> Vec<Case> {
+    let normalised = normalise(name);
+    let len = normalised.chars().count();
+    let scores = Case::ALL.iter().filter_map(|case| {
+        let score = iter::once(normalise(case.name()))
+            .chain(case.aliases().iter().map(|alias| (*alias).to_owned()))
+            .map(|candidate| {
+                if len >= MIN_PREFIX_LEN && candidate.starts_with(&normalised) {
+                    0
+                } else {
+                    distance(&normalised, &candidate)
+                }
+            })
+            .min()?;
+        (score.saturating_mul(3) <= len.saturating_add(3)).then_some((*case, score))
+    });
+    let close: Vec<_> = scores.collect();
+    let best = close.iter().map(|(_, score)| *score).min();
+    close
+        .into_iter()
+        .filter(|(_, score)| Some(*score) == best)
+        .map(|(case, _)| case)
+        .collect()
+}
+
+/// Computes the Levenshtein distance between two strings, i.e., the minimum number of
+/// insertions, deletions and substitutions of characters to go from one to the other.
+fn distance(left: &str, right: &str) -> usize {
+    let mut previous: Vec<usize> = (0..=right.chars().count()).collect();
+    for (left_index, left_char) in left.chars().enumerate() {
+        let mut current = vec![left_index.saturating_add(1)];
+        for ((diagonal, above), right_char) in previous
+            .iter()
+            .zip(previous.iter().skip(1))
+            .zip(right.chars())
+        {
+            let substitution = diagonal.saturating_add(usize::from(left_char != right_char));
+            let insertion = current
+                .last()
+                .map_or(usize::MAX, |last| last.saturating_add(1));
+            current.push(substitution.min(above.saturating_add(1)).min(insertion));
+        }
+        previous = current;
+    }
+    previous.last().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest_cases, distance};
+    use crate::parser::case::Case;
+
+    #[test]
+    fn distances() {
+        assert_eq!(distance("snak", "snake"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "dot"), 3);
+        assert_eq!(distance("dot", "dot"), 0);
+    }
+
+    #[test]
+    fn suggestions() {
+        assert_eq!(closest_cases("snak"), [Case::Snake]);
+        assert_eq!(closest_cases("SNAKE_CAS"), [Case::Snake]);
+        assert_eq!(closest_cases("pscal"), [Case::Pascal]);
+        assert_eq!(closest_cases("sent"), [Case::Sentence]);
+        assert_eq!(closest_cases("screming-snake"), [Case::Constant]);
+        assert_eq!(closest_cases("cam"), [Case::Camel]);
+        assert_eq!(closest_cases("upper"), [Case::Constant, Case::Pascal]);
+        assert_eq!(closest_cases("blob"), []);
+    }
+}
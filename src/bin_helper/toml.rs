@@ -0,0 +1,253 @@
+use std::io::{self, Read, Write};
+
+use crate::bin_helper::keys;
+use crate::parser::case::Case;
+
+/// Scanner of a TOML document, that writes it back with its keys converted.
+struct Recaser<'text> {
+    /// Case to convert the keys to.
+    case: Case,
+    /// Document written so far.
+    output: String,
+    /// Part of the document left to scan.
+    rest: &'text str,
+}
+
+impl Recaser<'_> {
+    /// Returns the key converted if it is a name, quoted if it can't be bare anymore, or else
+    /// unchanged.
+    fn convert_key(&self, key: &str) -> String {
+        match keys::convert_key(self.case, key) {
+            Some(converted)
+                if converted.starts_with(['"', '\'']) || converted.chars().all(is_bare) =>
+            {
+                converted
+            }
+            Some(converted) => format!("\"{converted}\""),
+            None => key.to_owned(),
+        }
+    }
+
+    /// Writes the given number of bytes of the rest unchanged.
+    fn copy(&mut self, length: usize) {
+        let (copied, rest) = self
+            .rest
+            .split_at_checked(length)
+            .unwrap_or((self.rest, ""));
+        self.output.push_str(copied);
+        self.rest = rest;
+    }
+
+    /// Writes the whitespace, line endings and comments unchanged.
+    fn copy_blank(&mut self) {
+        loop {
+            self.copy_while(|ch| matches!(ch, ' ' | '\t' | '\r' | '\n'));
+            if !self.rest.starts_with('#') {
+                return;
+            }
+            self.copy_while(|ch| ch != '\n');
+        }
+    }
+
+    /// Writes the spaces and tabs unchanged.
+    fn copy_spaces(&mut self) {
+        self.copy_while(|ch| matches!(ch, ' ' | '\t'));
+    }
+
+    /// Writes a string starting with the quote, single-line or multi-line, unchanged.
+    fn copy_string(&mut self, quote: char) {
+        let triple = quote.to_string().repeat(3);
+        let delimiter = if self.rest.starts_with(&triple) {
+            triple.as_str()
+        } else {
+            triple.get(..1).unwrap_or_default()
+        };
+        self.copy(string_len(self.rest, delimiter, quote == '"'));
+        if delimiter.len() > 1 {
+            self.copy_while(|ch| ch == quote);
+        }
+    }
+
+    /// Writes the characters unchanged while they match the predicate.
+    fn copy_while<Predicate: Fn(char) -> bool>(&mut self, predicate: Predicate) {
+        let length = self
+            .rest
+            .find(|ch| !predicate(ch))
+            .unwrap_or(self.rest.len());
+        self.copy(length);
+    }
+
+    /// Converts a key, which may be dotted, and writes it with the whitespace around it.
+    fn key(&mut self) {
+        loop {
+            self.copy_spaces();
+            let length = match self.rest.chars().next() {
+                Some('"') => string_len(self.rest, "\"", true),
+                Some('\'') => string_len(self.rest, "'", false),
+                Some(_) | None => self.rest.find(|ch| !is_bare(ch)).unwrap_or(self.rest.len()),
+            };
+            let Some((key, rest)) = self.rest.split_at_checked(length).filter(|_| length > 0)
+            else {
+                return;
+            };
+            let converted = self.convert_key(key);
+            self.output.push_str(&converted);
+            self.rest = rest;
+            self.copy_spaces();
+            if !self.rest.starts_with('.') {
+                return;
+            }
+            self.copy(1);
+        }
+    }
+
+    /// Scans the document, a table header or a key/value pair per line.
+    fn run(&mut self) {
+        loop {
+            self.copy_blank();
+            if self.rest.is_empty() {
+                return;
+            }
+            if self.rest.starts_with('[') {
+                self.copy_while(|ch| ch == '[');
+                self.key();
+                self.copy_while(|ch| ch == ']');
+            } else {
+                self.key();
+                if self.rest.starts_with('=') {
+                    self.copy(1);
+                    self.copy_spaces();
+                    self.value();
+                }
+            }
+            self.copy_while(|ch| ch != '\n');
+        }
+    }
+
+    /// Writes a value, with the keys of its inline tables converted.
+    fn value(&mut self) {
+        let Some(first) = self.rest.chars().next() else {
+            return;
+        };
+        match first {
+            '"' | '\'' => self.copy_string(first),
+            '[' | '{' => {
+                let end = if first == '[' { ']' } else { '}' };
+                self.copy(1);
+                loop {
+                    self.copy_blank();
+                    let Some(next) = self.rest.chars().next() else {
+                        return;
+                    };
+                    let length = self.rest.len();
+                    if next == end {
+                        self.copy(1);
+                        return;
+                    } else if next == ',' {
+                        self.copy(1);
+                    } else if first == '[' {
+                        self.value();
+                    } else {
+                        self.key();
+                        if self.rest.starts_with('=') {
+                            self.copy(1);
+                            self.copy_spaces();
+                            self.value();
+                        }
+                    }
+                    if self.rest.len() == length {
+                        self.copy(next.len_utf8());
+                    }
+                }
+            }
+            _ => self.copy_while(|ch| !matches!(ch, ',' | ']' | '}' | '#' | '\r' | '\n')),
+        }
+    }
+}
+
+/// Returns `true` iff the character can be part of a bare key.
+const fn is_bare(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'
+}
+
+/// Converts the keys of the TOML document of the reader to the case, and writes the document.
+///
+/// The keys of the tables, of the key/value pairs and of the inline tables are converted,
+/// everything else, comments and whitespace included, is written unchanged. Keys that aren't
+/// names, like `"127.0.0.1"`, are kept, and converted keys that can't be bare anymore are quoted.
+/// The document isn't validated: what can't be parsed is written unchanged.
+pub fn recase<R: Read, W: Write>(case: Case, mut reader: R, out: &mut W) -> io::Result<()> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    let mut recaser = Recaser {
+        case,
+        output: String::with_capacity(text.len()),
+        rest: &text,
+    };
+    recaser.run();
+    out.write_all(recaser.output.as_bytes())
+}
+
+/// Returns the length of the string at the start of the text, with its delimiters, or up to the
+/// end of the line or of the text if it isn't closed.
+fn string_len(text: &str, delimiter: &str, escapes: bool) -> usize {
+    let body = text.get(delimiter.len()..).unwrap_or_default();
+    let mut is_escaped = false;
+    for (index, ch) in body.char_indices() {
+        let length = delimiter.len().saturating_add(index);
+        match ch {
+            _ if is_escaped => is_escaped = false,
+            '\\' if escapes => is_escaped = true,
+            '\n' if delimiter.len() == 1 => return length,
+            _ if body
+                .get(index..)
+                .is_some_and(|rest| rest.starts_with(delimiter)) =>
+            {
+                return length.saturating_add(delimiter.len());
+            }
+            _ => (),
+        }
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "tests")]
+
+    use super::recase;
+    use crate::parser::case::Case;
+
+    fn test(case: Case, input: &str, expected: &str) {
+        let mut out = vec![];
+        recase(case, input.as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn keys() {
+        test(
+            Case::Snake,
+            "# Server settings\r\nlogLevel = \"debugMode\" # keep = this\r\n\r\n[httpServer.tlsConfig]\r\ncertFile = 'a.pem'\r\n\"maxConnections\" = 10\r\n'127.0.0.1' = true\r\n\r\n[[backendHosts]]\r\nhostName=\"x\"\r\n",
+            "# Server settings\r\nlog_level = \"debugMode\" # keep = this\r\n\r\n[http_server.tls_config]\r\ncert_file = 'a.pem'\r\n\"max_connections\" = 10\r\n'127.0.0.1' = true\r\n\r\n[[backend_hosts]]\r\nhost_name=\"x\"\r\n",
+        );
+    }
+
+    #[test]
+    fn values() {
+        test(
+            Case::Kebab,
+            "point = { xPos = 1, yPos = [2, 3], innerTable = {zIndex = 1979-05-27 07:32:00Z} }\nlistOf = [\n  # someKey = 1\n  { itemName = \"a]\" },\n  'b}',\n]\nlongText = \"\"\"\nnotAKey = \"1\"\nend\"\"\"\notherKey = '''\nnotAKey = 2'''\n",
+            "point = { x-pos = 1, y-pos = [2, 3], inner-table = {z-index = 1979-05-27 07:32:00Z} }\nlist-of = [\n  # someKey = 1\n  { item-name = \"a]\" },\n  'b}',\n]\nlong-text = \"\"\"\nnotAKey = \"1\"\nend\"\"\"\nother-key = '''\nnotAKey = 2'''\n",
+        );
+    }
+
+    #[test]
+    fn quoted() {
+        test(
+            Case::Dot,
+            "userName = 1\n'firstName' = 2\n\"last\\u0041Name\" = 3\n",
+            "\"user.name\" = 1\n'first.name' = 2\n\"last\\u0041Name\" = 3\n",
+        );
+    }
+}
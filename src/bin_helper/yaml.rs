@@ -0,0 +1,291 @@
+use std::io::{self, Read, Write};
+
+use crate::bin_helper::keys;
+use crate::parser::case::Case;
+
+/// Characters that start or end flow collections, or separate their entries.
+const FLOW_INDICATORS: [char; 5] = [',', '[', ']', '{', '}'];
+
+/// Scanner of YAML documents, a line at a time, that writes them back with their keys converted.
+struct Recaser {
+    /// Indentation of the node owning the current block scalar, whose lines are indented deeper.
+    block: Option<usize>,
+    /// Case to convert the keys to.
+    case: Case,
+    /// Opening brackets of the flow collections the scanner is in, from the outermost one.
+    flow: Vec<char>,
+    /// Whether a key is expected next, at the start of an entry of a flow mapping.
+    key_expected: bool,
+    /// Documents written so far.
+    output: String,
+    /// Quote of the scalar that continues on the next line, if any.
+    quote: Option<char>,
+}
+
+impl Recaser {
+    /// Scans a line of block context: the indentation, the entries of sequences, then a key and
+    /// its value.
+    fn block_line(&mut self, content: &str, indent: usize) {
+        let (spaces, mut rest) = content.split_at_checked(indent).unwrap_or((content, ""));
+        self.output.push_str(spaces);
+        let mut column = indent;
+        let mut parent = indent;
+        while let Some(after) = rest
+            .strip_prefix('-')
+            .filter(|after| after.is_empty() || after.starts_with([' ', '\t']))
+        {
+            let item = after.trim_start_matches([' ', '\t']);
+            let length = rest.len().saturating_sub(item.len());
+            self.output.push_str(rest.get(..length).unwrap_or_default());
+            parent = column;
+            column = column.saturating_add(length);
+            rest = item;
+        }
+        if let Some((key, value)) = key_len(rest).and_then(|length| rest.split_at_checked(length)) {
+            self.key(key);
+            parent = column;
+            rest = value;
+        }
+        self.value(rest, parent);
+    }
+
+    /// Converts a key, plain or quoted, if it is a name, and writes it with its trailing spaces.
+    fn key(&mut self, key: &str) {
+        let trimmed = key.trim_end_matches([' ', '\t']);
+        match keys::convert_key(self.case, trimmed) {
+            Some(converted) => {
+                self.output.push_str(&converted);
+                self.output
+                    .push_str(key.get(trimmed.len()..).unwrap_or_default());
+            }
+            None => self.output.push_str(key),
+        }
+    }
+
+    /// Scans a line, whose context depends on the previous ones.
+    fn line(&mut self, line: &str) {
+        let content = line.trim_end_matches(['\r', '\n']);
+        let ending = line.get(content.len()..).unwrap_or_default();
+        let indent = content
+            .len()
+            .saturating_sub(content.trim_start_matches(' ').len());
+        if let Some(parent) = self.block {
+            if content.trim().is_empty() || indent > parent {
+                self.output.push_str(line);
+                return;
+            }
+            self.block = None;
+        }
+        if !self.flow.is_empty() || self.quote.is_some() {
+            self.value(content, indent);
+        } else if content.starts_with('%')
+            || ["---", "..."].iter().any(|marker| {
+                content
+                    .strip_prefix(marker)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+            })
+        {
+            self.output.push_str(content);
+        } else {
+            self.block_line(content, indent);
+        }
+        self.output.push_str(ending);
+    }
+
+    /// Scans the value of a line, converting the keys of its flow mappings, and starts a block
+    /// scalar owned by the node at the `parent` indentation if it is one.
+    fn value(&mut self, text: &str, parent: usize) {
+        let mut rest = text;
+        let mut is_first = true;
+        while let Some(ch) = rest.chars().next() {
+            let mut is_key = false;
+            let length = if let Some(quote) = self.quote {
+                quote_end(rest, quote).map_or(rest.len(), |length| {
+                    self.quote = None;
+                    length
+                })
+            } else {
+                match ch {
+                    ' ' | '\t' | ':' => ch.len_utf8(),
+                    '#' => rest.len(),
+                    '|' | '>' if is_first && self.flow.is_empty() => {
+                        self.block = Some(parent);
+                        rest.len()
+                    }
+                    '[' | '{' => {
+                        self.flow.push(ch);
+                        self.key_expected = ch == '{';
+                        1
+                    }
+                    ']' | '}' => {
+                        self.flow.pop();
+                        self.key_expected = false;
+                        1
+                    }
+                    ',' if !self.flow.is_empty() => {
+                        self.key_expected = self.flow.last() == Some(&'{');
+                        1
+                    }
+                    '"' | '\'' => rest
+                        .get(1..)
+                        .and_then(|quoted| quote_end(quoted, ch))
+                        .map_or_else(
+                            || {
+                                self.quote = Some(ch);
+                                rest.len()
+                            },
+                            |length| length.saturating_add(1),
+                        ),
+                    _ if self.flow.is_empty() => rest.len(),
+                    _ => flow_plain_len(rest),
+                }
+            };
+            let (token, after) = rest.split_at_checked(length).unwrap_or((rest, ""));
+            if !matches!(ch, ' ' | '\t' | ':' | ',' | '[' | ']' | '{' | '}') {
+                is_key =
+                    self.key_expected && after.trim_start_matches([' ', '\t']).starts_with(':');
+                self.key_expected = false;
+            }
+            if is_key {
+                self.key(token);
+            } else {
+                self.output.push_str(token);
+            }
+            is_first = is_first && matches!(ch, ' ' | '\t' | ':');
+            rest = after;
+        }
+    }
+}
+
+/// Returns the length of the plain scalar at the start of the text, in a flow collection.
+fn flow_plain_len(text: &str) -> usize {
+    let mut previous = ' ';
+    for (index, ch) in text.char_indices() {
+        let next = text
+            .get(index.saturating_add(ch.len_utf8())..)
+            .and_then(|rest| rest.chars().next());
+        if FLOW_INDICATORS.contains(&ch)
+            || (ch == '#' && previous.is_whitespace())
+            || (ch == ':'
+                && next.is_none_or(|following| {
+                    following.is_whitespace() || FLOW_INDICATORS.contains(&following)
+                }))
+        {
+            return index;
+        }
+        previous = ch;
+    }
+    text.len()
+}
+
+/// Returns the length of the key at the start of the text, up to its `:`, if it starts with one.
+fn key_len(text: &str) -> Option<usize> {
+    let key_end = match text.chars().next()? {
+        quote @ ('"' | '\'') => quote_end(text.get(1..)?, quote)?.saturating_add(1),
+        '[' | ']' | '{' | '}' | ',' | '&' | '*' | '!' | '|' | '>' | '%' | '@' | '`' | '#' | '?'
+        | ':' => return None,
+        _ => {
+            let mut previous = ' ';
+            for (index, ch) in text.char_indices() {
+                let next = text
+                    .get(index.saturating_add(1)..)
+                    .and_then(|rest| rest.chars().next());
+                if ch == '#' && previous.is_whitespace() {
+                    return None;
+                }
+                if ch == ':' && next.is_none_or(|following| matches!(following, ' ' | '\t')) {
+                    return Some(index);
+                }
+                previous = ch;
+            }
+            return None;
+        }
+    };
+    let after = text.get(key_end..)?;
+    let colon = after.trim_start_matches([' ', '\t']);
+    let after_colon = colon.strip_prefix(':')?;
+    (after_colon.is_empty() || after_colon.starts_with([' ', '\t']))
+        .then(|| key_end.saturating_add(after.len().saturating_sub(colon.len())))
+}
+
+/// Returns the length of the text up to the closing quote included, if it is on this line.
+///
+/// Double quotes are escaped with backslashes, and single quotes by doubling them.
+fn quote_end(text: &str, quote: char) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\\' if quote == '"' => {
+                chars.next();
+            }
+            _ if ch != quote => (),
+            '\'' if chars.next_if(|&(_, following)| following == '\'').is_some() => (),
+            _ => return Some(index.saturating_add(1)),
+        }
+    }
+    None
+}
+
+/// Converts the keys of the YAML documents of the reader to the case, and writes the documents.
+///
+/// The keys of the block and flow mappings are converted, everything else, comments, block
+/// scalars and whitespace included, is written unchanged. Keys that aren't names, like
+/// `app.kubernetes.io/name` or the `<<` merge key, are kept. The documents aren't validated: what
+/// can't be parsed is written unchanged.
+pub fn recase<R: Read, W: Write>(case: Case, mut reader: R, out: &mut W) -> io::Result<()> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    let mut recaser = Recaser {
+        block: None,
+        case,
+        flow: vec![],
+        key_expected: false,
+        output: String::with_capacity(text.len()),
+        quote: None,
+    };
+    for line in text.split_inclusive('\n') {
+        recaser.line(line);
+    }
+    out.write_all(recaser.output.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "tests")]
+
+    use super::recase;
+    use crate::parser::case::Case;
+
+    fn test(case: Case, input: &str, expected: &str) {
+        let mut out = vec![];
+        recase(case, input.as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn block() {
+        test(
+            Case::Snake,
+            "%YAML 1.2\n---\n# apiVersion: v1\napiVersion: apps/v1\nmetadata:\n  labels:\n    app.kubernetes.io/name: webApp # appName: x\n  \"displayName\" : 'Web: App'\n  'it''s': 1\nspec:\n  - containerPort: 80\n    hostIP: \"0.0.0.0\"\n  - - nestedKey: 1\n    -\n      url: http://example.com:80/a\n<<: *defaults\n...\n",
+            "%YAML 1.2\n---\n# apiVersion: v1\napi_version: apps/v1\nmetadata:\n  labels:\n    app.kubernetes.io/name: webApp # appName: x\n  \"display_name\" : 'Web: App'\n  'it''s': 1\nspec:\n  - container_port: 80\n    host_ip: \"0.0.0.0\"\n  - - nested_key: 1\n    -\n      url: http://example.com:80/a\n<<: *defaults\n...\n",
+        );
+    }
+
+    #[test]
+    fn block_scalars() {
+        test(
+            Case::Kebab,
+            "scriptLines: |\n  firstKey: 1\n\n  secondKey: 2\nfoldedText: >-\n    aKey: b\nitems:\n  - |\n    notAKey: 1\n  - otherKey: 1\nlastKey: 1\r\n",
+            "script-lines: |\n  firstKey: 1\n\n  secondKey: 2\nfolded-text: >-\n    aKey: b\nitems:\n  - |\n    notAKey: 1\n  - other-key: 1\nlast-key: 1\r\n",
+        );
+    }
+
+    #[test]
+    fn flow() {
+        test(
+            Case::Camel,
+            "ports: [{container_port: 80, \"host_ip\": x}, [a, b]]\nlimits: {max_cpu: 1,\n  max_memory: {soft_limit: \"1: 2\"}}\nnote: \"multi_line: a\n  not_a_key: b\"\nurls: [http://a_b:80]\n",
+            "ports: [{containerPort: 80, \"hostIp\": x}, [a, b]]\nlimits: {maxCpu: 1,\n  maxMemory: {softLimit: \"1: 2\"}}\nnote: \"multi_line: a\n  not_a_key: b\"\nurls: [http://a_b:80]\n",
+        );
+    }
+}
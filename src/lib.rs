@@ -29,6 +29,12 @@
 
 /// Parsing logic to perform conversion between cases.
 mod parser;
+/// Support for [`serde`](::serde), behind the `serde` feature.
+#[cfg(feature = "serde")]
+pub mod serde;
 
-pub use parser::case::Case;
+pub use parser::bem::Bem;
+pub use parser::case::{Case, ParseCaseError};
+pub use parser::case_cycle::CaseCycle;
 pub use parser::caseify::Caseify;
+pub use parser::convention::{Convention, IdentKind, ParseConventionError, ParseIdentKindError};
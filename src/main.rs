@@ -39,26 +39,34 @@ mod bin_helper;
 /// Parsing logic to perform conversion between cases.
 mod parser;
 use std::env;
+use std::process::ExitCode;
 
 use crate::bin_helper::cli::Cli;
-use crate::bin_helper::status::Status;
 
 /// Parses the input from the arguments given to the CLI, and runs caseify on it.
+///
+/// On failure, returns the exit code of the process with the message to print.
 #[expect(clippy::unwrap_used, reason = "argv always has at least 1 element")]
-fn parse_and_run<Args: Iterator<Item = String>>(mut args: Args) -> Result<(), String> {
+fn parse_and_run<Args: Iterator<Item = String>>(mut args: Args) -> Result<(), (u8, String)> {
     let program_name = args.next().unwrap();
     Cli::parse(&mut args)
-        .and_then(|cli| {
-            cli.run()
-                .map_err(|err| Status::Error(format!("Io Error: {err}")))
-        })
-        .map_err(|err| err.to_string(&program_name))
+        .and_then(|cli| cli.run())
+        .map_err(|status| (status.exit_code(), status.to_string(&program_name)))
 }
 
-#[expect(clippy::print_stderr, reason = "cli")]
-fn main() {
-    if let Err(err) = parse_and_run(env::args()) {
-        eprintln!("{err}");
+#[expect(clippy::print_stderr, clippy::print_stdout, reason = "cli")]
+fn main() -> ExitCode {
+    match parse_and_run(env::args()) {
+        Ok(()) => ExitCode::SUCCESS,
+        // The help message is the only successful output on failure
+        Err((0, help)) => {
+            println!("{help}");
+            ExitCode::SUCCESS
+        }
+        Err((code, err)) => {
+            eprintln!("{err}");
+            ExitCode::from(code)
+        }
     }
 }
 
@@ -66,81 +74,437 @@ fn main() {
 mod test_errors {
     #![expect(clippy::unwrap_used, reason = "tests should fail")]
 
-    use std::env;
     use std::sync::Mutex;
+    use std::{env, fs, process};
 
     use crate::parse_and_run;
 
     static TERM: Mutex<()> = Mutex::new(());
 
-    const HELP: &str = "Usage: caseify <case> [value] [--help]
-Omit `value` to read from stdin (e.g. for pipes)
+    const HELP: &str = "Usage: caseify [options] <case> [values...]
+Omit `values` to read from stdin (e.g. for pipes), keeping the line endings
 
-Possible case values:
-  Camel        `camelCase`
-  Capitalised  `Capitalised Case`
-  Constant     `CONSTANT_CASE`
-  Dot          `dot.case`
-  Kebab        `kebab-case`
-  Pascal       `PascalCase`
-  Sentence     `Sentence case`
-  Snake        `snake_case`
+Possible case values, also accepted in any case or as an alias (e.g. `snake_case`):
+  Camel        -c, --camel        `camelCase`
+  Capitalised  -t, --capitalised  `Capitalised Case`
+  Constant     -C, --constant     `CONSTANT_CASE`
+  CssVar       -v, --css-var      `--css-var`
+  Dot          -d, --dot          `dot.case`
+  Kebab        -k, --kebab        `kebab-case`
+  Pascal       -p, --pascal       `PascalCase`
+  Sentence     -S, --sentence     `Sentence case`
+  Snake        -s, --snake        `snake_case`
+
+Options
+  -<short>, --<case>  Case to convert to, instead of the first argument
+  --case <case>       Case to convert to, by name or alias
+  -j, --join          Join the values with spaces into a single phrase
+  -f, --files         Convert the lines of the files given as values
+  -i, --in-place      Rewrite the files in place (`--in-place=<suffix>` keeps a backup)
+  -0, --null          Separate the records of stdin and files with NUL instead of lines
+  --delimiter <str>   Separate the records of stdin and files with the given string
+  --invalid <mode>    Handle invalid UTF-8 input: `error` (default), `lossy` or `pass`
+  --jobs <n>          Convert the records of stdin and files on n threads, in order
+  --fields <list>     Only convert the given fields of the records, like `1,3` (or `--field`)
+  --separator <str>   Separate the fields with the given string instead of tabs
+  --csv               Read the fields as CSV, with quotes and commas by default
+  --header-only       Only convert the fields of the first record
+  --match [<glob>]    Only convert identifiers in text, or the words matching a glob
+  --depth <n>         Only convert the keys of the n outermost levels of JSON objects
+  --include <glob>    Only convert the JSON keys that match the glob, can be repeated
+  --exclude <glob>    Don't convert the JSON keys that match the glob, can be repeated
+  --values            Convert the JSON string values too
+  --pretty            Indent the JSON output by two spaces
+  --compact           Write the JSON output without whitespace, a document per line
+  --lang <name>       Language of the sources: `c`, `go`, `js`, `py`, `rust` or `ts`
+  --kind <list>       Only convert the given kinds of identifiers, like `local,field`
+  --fix               Rename the private names that break the convention with `lint`
+  --porcelain         Print the results of `detect` as `<cases>\\t<value>` lines
+  -r, --recursive     Rename the contents of the directories too
+  -n, --dry-run       Print the renames without performing them
+  -h, --help          Print this help message
+  --                  Treat the following arguments as values, even if they start with `-`
+
+Use cycle as case to convert to the next case of the cycle:
+  Snake -> Camel -> Pascal -> Kebab -> Constant -> Dot -> Sentence -> Capitalised -> CssVar
+
+Use detect as case to print the cases the values are already in.
+Use check <case> to report the values that aren't in case, and fail if there are any.
+Use rename <case> to rename the files and directories given as values, keeping all their extensions.
+Use json <case> to convert the keys of the JSON documents of the files given as values, or of stdin.
+Use yaml or toml <case> to convert the keys of the files given as values, or of stdin, keeping comments.
+Use env or ini <case> to do the same for .env, INI and properties files, and warn about colliding keys.
+Use code <case> to convert the identifiers declared in the source files given as values, keeping strings and comments.
+Use convention <language> <kind> to convert the values to the case of that kind of identifiers in the language, like `rust type`.
+Use lint <language> to report the names declared in the files and directories given as values that break the convention of the language.
 
 Examples
 $ caseify Camel \"Hello World\"
 # Output: helloWorld
 $ echo \"hello World\" | caseify Constant
-# Output: HELLO_WORLD";
+# Output: HELLO_WORLD
+$ caseify \"SomeVariableName\" --snake
+# Output: some_variable_name
+$ caseify Snake hello world --join
+# Output: hello_world
+$ caseify cycle \"hello_world\"
+# Output: helloWorld
+$ caseify detect \"foo_bar\"
+# Output: foo_bar: Snake
+$ caseify check Snake \"foo_bar\" \"fooBar\"
+# Output: <args>:2: fooBar -> foo_bar
+$ caseify rename Kebab -r \"My Photos\"
+# Output: My Photos -> my-photos
+$ echo '{\"userName\": 1}' | caseify json Snake
+# Output: {\"user_name\": 1}
+$ echo \"apiVersion: v1\" | caseify yaml Snake
+# Output: api_version: v1
+$ echo \"database.url=x\" | caseify env Constant
+# Output: DATABASE_URL=x
+$ echo \"let user_id = 1;\" | caseify code Camel --lang rust
+# Output: let userId = 1;
+$ caseify convention python constant maxSize
+# Output: MAX_SIZE
+$ caseify lint rust src
+# Output: src/lib.rs:3:4: function getName -> get_name (Snake case)
+
+Exit status
+  0   Success
+  1   Check failed
+  64  Usage error
+  65  Invalid input (e.g. invalid UTF-8)
+  74  IO error";
 
-    fn expect_stderr(args: &[&str], output: &str) {
+    fn expect_output(args: &[&str], code: u8, output: &str) {
         let x = TERM.lock().unwrap();
         // SAFETY: TERM locked
         unsafe { env::set_var("TERM", "dumb") }
 
         assert_eq!(
             parse_and_run(args.iter().map(|arg| (*arg).to_owned())),
-            Err(output.to_owned())
+            Err((code, output.to_owned()))
         );
         drop(x);
     }
 
     #[test]
     fn help() {
-        expect_stderr(&["caseify", "--help"], HELP);
-        expect_stderr(&["caseify", "Pascal", "--help"], HELP);
-        expect_stderr(&["caseify", "Pascal", "hello, world!", "--help"], HELP);
+        expect_output(&["caseify", "--help"], 0, HELP);
+        expect_output(&["caseify", "Pascal", "--help"], 0, HELP);
+        expect_output(&["caseify", "Pascal", "hello, world!", "--help"], 0, HELP);
+        expect_output(&["caseify", "--blob", "-h"], 0, HELP);
     }
 
     fn test_error(args: &[&str], err: &str) {
-        expect_stderr(
+        expect_output(
             args,
-            &format!("Failed to run caseify: {err}\n\nUsage: caseify <case> [value] [--help]"),
+            64,
+            &format!("Failed to run caseify: {err}\n\nUsage: caseify [options] <case> [values...]"),
         );
     }
 
     #[test]
     fn invalid_case() {
         test_error(&["caseify", "blob"], "blob isn't a valid case");
+        test_error(
+            &["caseify", "snak"],
+            "snak isn't a valid case, did you mean `Snake`?\n\n  Snake        -s, --snake  `snake_case`",
+        );
+        test_error(
+            &["caseify", "--case=upper"],
+            "upper isn't a valid case, did you mean one of these?\n\n  Constant     -C, --constant  `CONSTANT_CASE`\n  Pascal       -p, --pascal    `PascalCase`",
+        );
     }
 
     #[test]
-    fn too_many_args() {
+    fn missing_case() {
+        test_error(&["caseify"], "Missing `case` argument");
+        test_error(&["caseify", "--case"], "Missing value for `--case`");
+    }
+
+    #[test]
+    fn invalid_options() {
+        test_error(&["caseify", "--blob"], "Unknown option `--blob`");
+        test_error(&["caseify", "-x"], "Unknown option `-x`");
+        test_error(
+            &["caseify", "--snak"],
+            "Unknown option `--snak`, did you mean `Snake`?\n\n  Snake        -s, --snake  `snake_case`",
+        );
+        test_error(
+            &["caseify", "--kebap", "foo"],
+            "Unknown option `--kebap`, did you mean `Kebab`?\n\n  Kebab        -k, --kebab  `kebab-case`",
+        );
+        test_error(&["caseify", "--snake=x"], "`--snake` doesn't take a value");
+        test_error(&["caseify", "--case=blob"], "blob isn't a valid case");
+        test_error(&["caseify", "--snake", "-c"], "Too many cases");
+        test_error(&["caseify", "--join=yes"], "`--join` doesn't take a value");
+        test_error(
+            &["caseify", "--files=yes"],
+            "`--files` doesn't take a value",
+        );
+        test_error(
+            &["caseify", "Snake", "--porcelain"],
+            "`--porcelain` can only be used with `detect`",
+        );
         test_error(
-            &["caseify", "Pascal", "hello", "world"],
-            "Too many arguments",
+            &["caseify", "detect", "-i", "names.txt"],
+            "`detect` can't edit files in place",
         );
     }
 
     #[test]
-    fn missing_case() {
-        test_error(&["caseify"], "Missing `case` argument");
+    fn invalid_records() {
+        test_error(
+            &["caseify", "Snake", "--delimiter"],
+            "Missing value for `--delimiter`",
+        );
+        test_error(
+            &["caseify", "Snake", "--delimiter="],
+            "`--delimiter` can't be empty",
+        );
+        test_error(
+            &["caseify", "Snake", "--null=x"],
+            "`--null` doesn't take a value",
+        );
+        test_error(
+            &["caseify", "Snake", "--jobs=0"],
+            "Invalid value `0` for `--jobs`, expected a positive number",
+        );
+        test_error(
+            &["caseify", "check", "Snake", "--jobs", "4"],
+            "`--jobs` can only be used to convert or detect",
+        );
+        test_error(
+            &["caseify", "Snake", "--fields", "1,x"],
+            "Invalid value `1,x` for `--fields`, expected field numbers like `1,3`",
+        );
+        test_error(
+            &["caseify", "Snake", "--separator="],
+            "`--separator` can't be empty",
+        );
+        test_error(
+            &["caseify", "detect", "--csv"],
+            "`--fields`, `--separator`, `--csv` and `--header-only` can only be used to convert",
+        );
+        test_error(
+            &["caseify", "Snake", "--match="],
+            "`--match` can't be empty",
+        );
+        test_error(
+            &["caseify", "check", "Snake", "--match"],
+            "`--match` can only be used to convert",
+        );
+        test_error(
+            &["caseify", "Snake", "--invalid=skip"],
+            "Invalid value `skip` for `--invalid`, expected `error`, `lossy` or `pass`",
+        );
+    }
+
+    #[test]
+    fn invalid_files() {
+        test_error(&["caseify", "Snake", "-f"], "Missing file paths");
+        test_error(&["caseify", "Snake", "-i"], "Missing file paths");
+        test_error(
+            &["caseify", "Snake", "-fj", "names.txt"],
+            "`--join` can't be used with files",
+        );
+    }
+
+    #[test]
+    fn io_errors() {
+        expect_output(
+            &["caseify", "Snake", "-f", "missing.txt"],
+            74,
+            "Failed to run caseify: missing.txt: No such file or directory (os error 2)",
+        );
+
+        let path = env::temp_dir().join(format!("caseify-invalid-{}.txt", process::id()));
+        fs::write(&path, b"hello\xffworld").unwrap();
+        let path_str = path.to_str().unwrap();
+        expect_output(
+            &["caseify", "Snake", "-f", path_str],
+            65,
+            &format!(
+                "Failed to run caseify: {path_str}: line 1: invalid utf-8 sequence of 1 bytes from index 5"
+            ),
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(
+            parse_and_run(
+                ["caseify", "check", "Snake", "foo_bar"]
+                    .map(str::to_owned)
+                    .into_iter()
+            ),
+            Ok(())
+        );
+        test_error(&["caseify", "check"], "Missing `case` argument for `check`");
+        test_error(
+            &["caseify", "check", "Snake", "-i", "names.txt"],
+            "`check` can't edit files in place",
+        );
+        test_error(
+            &[
+                "caseify",
+                "check",
+                "Snake",
+                "--invalid=pass",
+                "-f",
+                "names.txt",
+            ],
+            "`check` can't pass invalid UTF-8 through, use `--invalid=error` or `lossy`",
+        );
+    }
+
+    #[test]
+    fn rename() {
+        test_error(
+            &["caseify", "rename", "Snake"],
+            "Missing paths for `rename`",
+        );
+        test_error(
+            &["caseify", "rename", "Snake", "-f", "names.txt"],
+            "`rename` only takes paths, without `--files`, `--in-place` or `--join`",
+        );
+        test_error(
+            &["caseify", "Snake", "-r", "names.txt"],
+            "`--dry-run` and `--recursive` can only be used with `rename`",
+        );
+    }
+
+    #[test]
+    fn json() {
+        test_error(&["caseify", "json"], "Missing `case` argument for `json`");
+        test_error(
+            &["caseify", "json", "Snake", "--depth=x"],
+            "Invalid value `x` for `--depth`, expected a number",
+        );
+        test_error(
+            &["caseify", "json", "Snake", "--jobs=2"],
+            "`env`, `ini`, `json`, `toml` and `yaml` read whole documents, without `--join`, `--jobs`, `--match`, record or field options",
+        );
+        test_error(
+            &["caseify", "Snake", "--pretty"],
+            "`--depth`, `--include`, `--exclude`, `--values`, `--pretty` and `--compact` can only be used with `json`",
+        );
+        expect_output(
+            &["caseify", "json", "Snake", "missing.json"],
+            74,
+            "Failed to run caseify: missing.json: No such file or directory (os error 2)",
+        );
+    }
+
+    #[test]
+    fn yaml_and_toml() {
+        test_error(&["caseify", "yaml"], "Missing `case` argument for `yaml`");
+        test_error(
+            &["caseify", "toml", "Snake", "--csv"],
+            "`env`, `ini`, `json`, `toml` and `yaml` read whole documents, without `--join`, `--jobs`, `--match`, record or field options",
+        );
+        test_error(
+            &["caseify", "yaml", "Snake", "--values"],
+            "`--depth`, `--include`, `--exclude`, `--values`, `--pretty` and `--compact` can only be used with `json`",
+        );
+        expect_output(
+            &["caseify", "toml", "Snake", "missing.toml"],
+            74,
+            "Failed to run caseify: missing.toml: No such file or directory (os error 2)",
+        );
+    }
+
+    #[test]
+    fn env_and_ini() {
+        test_error(&["caseify", "env"], "Missing `case` argument for `env`");
+        test_error(
+            &["caseify", "ini", "Snake", "--jobs=2"],
+            "`env`, `ini`, `json`, `toml` and `yaml` read whole documents, without `--join`, `--jobs`, `--match`, record or field options",
+        );
+    }
+
+    #[test]
+    fn code() {
+        test_error(
+            &["caseify", "code", "Snake"],
+            "`code` needs `--lang` to read stdin",
+        );
+        test_error(
+            &["caseify", "code", "Snake", "--lang=cobol"],
+            "Invalid value `cobol` for `--lang`, expected `c`, `go`, `js`, `py`, `rust` or `ts`",
+        );
+        test_error(
+            &[
+                "caseify",
+                "code",
+                "Snake",
+                "--kind=variable,module2",
+                "a.rs",
+            ],
+            "Invalid value `module2` for `--kind`, expected `constant`, `enum-variant`, `field`, `file-name`, `function`, `local`, `module` or `type`",
+        );
+        test_error(
+            &["caseify", "Snake", "--kind=variable"],
+            "`--kind` can only be used with `code` and `lint`",
+        );
+        test_error(
+            &["caseify", "json", "Snake", "--lang=rust"],
+            "`--lang` can only be used with `code`",
+        );
+        test_error(
+            &["caseify", "code", "Snake", "--join", "a.rs"],
+            "`code` reads whole sources, without `--join`, `--jobs`, record or field options",
+        );
+    }
+
+    #[test]
+    fn convention() {
+        test_error(
+            &["caseify", "convention"],
+            "Missing `language` argument for `convention`",
+        );
+        test_error(
+            &["caseify", "convention", "rust"],
+            "Missing `kind` argument for `convention`",
+        );
+        test_error(
+            &["caseify", "convention", "cobol", "type"],
+            "cobol isn't a valid convention, expected `c`, `csharp`, `go`, `java`, `javascript`, `kotlin`, `python`, `rust`, `sql` or `swift`",
+        );
+        test_error(
+            &["caseify", "convention", "rust", "macro", "foo"],
+            "macro isn't a valid identifier kind, expected `constant`, `enum-variant`, `field`, `file-name`, `function`, `local`, `module` or `type`",
+        );
+    }
+
+    #[test]
+    fn lint() {
+        test_error(
+            &["caseify", "lint"],
+            "Missing `language` argument for `lint`",
+        );
+        test_error(
+            &["caseify", "lint", "cobol", "src"],
+            "Invalid value `cobol` for `lint`, expected `c`, `go`, `js`, `py`, `rust` or `ts`",
+        );
+        test_error(&["caseify", "lint", "rust"], "Missing paths for `lint`");
+        test_error(
+            &["caseify", "lint", "rust", "-i", "src"],
+            "`lint` only takes paths, without `--files`, `--in-place` or `--join`",
+        );
+        test_error(
+            &["caseify", "code", "Snake", "--fix", "a.rs"],
+            "`--fix` can only be used with `lint`",
+        );
     }
 
     #[test]
     fn program_name() {
-        expect_stderr(
+        expect_output(
             &["some_program_name"],
-            "Failed to run some_program_name: Missing `case` argument\n\nUsage: some_program_name <case> [value] [--help]",
+            64,
+            "Failed to run some_program_name: Missing `case` argument\n\nUsage: some_program_name [options] <case> [values...]",
         );
     }
 
@@ -154,7 +518,7 @@ $ echo \"hello World\" | caseify Constant
 
         assert_eq!(
             parse_and_run(args.iter().map(|arg| (*arg).to_owned())),
-            Err("\x1b[31mFailed to run caseify: Missing `case` argument\x1b[0m\n\n\x1b[35mUsage: caseify <\x1b[32mcase\x1b[35m> [value] [--help]\x1b[0m".to_owned())
+            Err((64, "\x1b[31mFailed to run caseify: Missing `case` argument\x1b[0m\n\n\x1b[35mUsage: caseify [options] <\x1b[32mcase\x1b[35m> [values...]\x1b[0m".to_owned()))
         );
         drop(x);
     }
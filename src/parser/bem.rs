@@ -0,0 +1,97 @@
+#![allow(dead_code, reason = "in lib but not in bin")]
+
+use core::fmt;
+
+use super::caseify::Caseify as _;
+
+/// Separator between the block and the element of a BEM class.
+const ELEMENT_SEPARATOR: &str = "__";
+/// Separator before the modifier of a BEM class.
+const MODIFIER_SEPARATOR: &str = "--";
+
+/// Class name following the BEM convention (`block__element--modifier`).
+///
+/// Every part is stored in `kebab-case`, and the class is obtained with [`ToString`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Bem {
+    /// Block of the class, in `kebab-case`.
+    pub block: String,
+    /// Element of the class, in `kebab-case`, if any.
+    pub element: Option<String>,
+    /// Modifier of the class, in `kebab-case`, if any.
+    pub modifier: Option<String>,
+}
+
+impl Bem {
+    /// Builds a BEM class from identifiers in any case.
+    ///
+    /// Empty identifiers for the element or the modifier are ignored.
+    #[must_use]
+    pub fn new(block: &str, element: Option<&str>, modifier: Option<&str>) -> Self {
+        let to_part = |part: &str| Some(part.to_kebab_case()).filter(|kebab| !kebab.is_empty());
+        Self {
+            block: block.to_kebab_case(),
+            element: element.and_then(to_part),
+            modifier: modifier.and_then(to_part),
+        }
+    }
+
+    /// Parses a BEM class back into its block, element and modifier.
+    ///
+    /// Returns `None` if the block, or one of the parts introduced by a separator, isn't in
+    /// `kebab-case`, like an empty part, a part with a leading, trailing or doubled `-`, or a
+    /// second element.
+    #[must_use]
+    pub fn parse(class: &str) -> Option<Self> {
+        let (rest, modifier) = match class.split_once(MODIFIER_SEPARATOR) {
+            Some((rest, modifier)) => (rest, Some(modifier)),
+            None => (class, None),
+        };
+        let (block, element) = match rest.split_once(ELEMENT_SEPARATOR) {
+            Some((block, element)) => (block, Some(element)),
+            None => (rest, None),
+        };
+        let is_valid =
+            is_kebab(block) && element.is_none_or(is_kebab) && modifier.is_none_or(is_kebab);
+        is_valid.then(|| Self {
+            block: block.to_owned(),
+            element: element.map(str::to_owned),
+            modifier: modifier.map(str::to_owned),
+        })
+    }
+
+    /// Returns the words of the block, the element and the modifier, in that order.
+    #[must_use]
+    pub fn words(&self) -> [Vec<&str>; 3] {
+        [
+            Some(self.block.as_str()),
+            self.element.as_deref(),
+            self.modifier.as_deref(),
+        ]
+        .map(|part| part.map_or_else(Vec::new, |kebab| kebab.split('-').collect()))
+    }
+}
+
+impl fmt::Display for Bem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.block)?;
+        if let Some(element) = &self.element {
+            write!(f, "{ELEMENT_SEPARATOR}{element}")?;
+        }
+        if let Some(modifier) = &self.modifier {
+            write!(f, "{MODIFIER_SEPARATOR}{modifier}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` iff the part of a class is made of lowercase words separated by single `-`.
+fn is_kebab(part: &str) -> bool {
+    part.split('-').all(|word| {
+        !word.is_empty()
+            && word
+                .chars()
+                .all(|ch| ch.is_alphanumeric() && !ch.is_uppercase())
+    })
+}
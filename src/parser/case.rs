@@ -1,12 +1,17 @@
 #![allow(dead_code, reason = "in bin but not in lib")]
 
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use super::case_cycle::CaseCycle;
 use super::caseify::Caseify as _;
 
 /// Creates the [`Case`] struct and its methods.
 macro_rules! make_case {
-    ($($upper:ident: $lower:ident: $doc:literal),*) => {
+    ($($upper:ident: $lower:ident: $doc:literal: [$($alias:literal),*]),*) => {
         /// Supported cases
-        #[derive(Copy, Clone, Debug)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
         #[non_exhaustive]
         pub enum Case {
             $(
@@ -16,9 +21,20 @@ macro_rules! make_case {
         }
 
         impl Case {
+            /// List of all the cases, in alphabetical order.
+            pub const ALL: &[Self] = &[$(Self::$upper),*];
+
             /// List of supports cases
             pub(crate) const HELP: &[(&str, &str)] = &[$((stringify!($upper), $doc)),*];
 
+            /// Returns the aliases accepted when parsing the case, on top of its name and example.
+            ///
+            /// The aliases are normalised with [`normalise`].
+            pub(crate) const fn aliases(self) -> &'static [&'static str] {
+                match self {
+                    $(Self::$upper => &[$($alias),*],)*
+                }
+            }
 
             /// Transforms the input in the given case and returns it.
             #[must_use]
@@ -28,11 +44,10 @@ macro_rules! make_case {
                 }
             }
 
-            /// Creates a [`Case`] type by parsing from a string.
-            pub(crate) fn maybe_from(case: &str) -> Option<Self> {
-                match case {
-                    $(stringify!($upper) => Some(Self::$upper),)*
-                    _ => None
+            /// Returns the name of the case, as written in the enum.
+            pub(crate) const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$upper => stringify!($upper),)*
                 }
             }
         }
@@ -41,12 +56,96 @@ macro_rules! make_case {
 }
 
 make_case!(
-    Camel: to_camel_case: "`camelCase`",
-    Capitalised: to_capitalised_case: "`Capitalised Case`",
-    Constant: to_constant_case: "`CONSTANT_CASE`",
-    Dot: to_dot_case: "`dot.case`",
-    Kebab: to_kebab_case: "`kebab-case`",
-    Pascal: to_pascal_case: "`PascalCase`",
-    Sentence: to_sentence_case: "`Sentence case`",
-    Snake: to_snake_case: "`snake_case`"
+    Camel: to_camel_case: "`camelCase`": ["lower_camel"],
+    Capitalised: to_capitalised_case: "`Capitalised Case`": ["capitalized", "title"],
+    Constant: to_constant_case: "`CONSTANT_CASE`": ["screaming_snake", "upper_snake"],
+    CssVar: to_css_var_case: "`--css-var`": ["css_custom_property", "css_variable"],
+    Dot: to_dot_case: "`dot.case`": [],
+    Kebab: to_kebab_case: "`kebab-case`": ["dash", "spinal"],
+    Pascal: to_pascal_case: "`PascalCase`": ["upper_camel"],
+    Sentence: to_sentence_case: "`Sentence case`": [],
+    Snake: to_snake_case: "`snake_case`": []
 );
+
+impl Case {
+    /// Returns all the cases the input is already in.
+    ///
+    /// Short inputs are often ambiguous: `foo` is in `camelCase`, `dot.case`, `kebab-case` and
+    /// `snake_case` at the same time.
+    #[must_use]
+    pub fn detect(input: &str) -> Vec<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|case| case.matches(input))
+            .collect()
+    }
+
+    /// Returns an iterator over all the cases, in alphabetical order.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Returns `true` iff the input is already in the given case.
+    #[must_use]
+    pub fn matches(self, input: &str) -> bool {
+        self.caseify(input) == input
+    }
+
+    /// Returns the next case of the default [`CaseCycle`].
+    #[must_use]
+    pub fn next(self) -> Self {
+        CaseCycle::default().next(self)
+    }
+
+    /// Returns the previous case of the default [`CaseCycle`].
+    #[must_use]
+    pub fn prev(self) -> Self {
+        CaseCycle::default().prev(self)
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Case {
+    type Err = ParseCaseError;
+
+    /// Parses a case from its name (`Snake`), in any case (`snake`, `SNAKE`), with or without the
+    /// `case` suffix (`snake_case`, `snake-case`), from one of its aliases (`SCREAMING_SNAKE`) or
+    /// from its example spelling (`CONSTANT_CASE`).
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let normalised = normalise(name);
+        Self::iter()
+            .find(|case| {
+                normalise(case.name()) == normalised || case.aliases().contains(&&*normalised)
+            })
+            .ok_or_else(|| ParseCaseError(name.to_owned()))
+    }
+}
+
+/// Error returned when parsing a [`Case`] from an unknown name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCaseError(String);
+
+impl fmt::Display for ParseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} isn't a valid case", self.0)
+    }
+}
+
+#[expect(clippy::missing_trait_methods, reason = "no source error")]
+impl Error for ParseCaseError {}
+
+/// Normalises a case name to compare it with the names and aliases of the cases.
+///
+/// The name is converted to `snake_case`, without the `_case` suffix.
+pub fn normalise(name: &str) -> String {
+    let snake = name.to_snake_case();
+    snake
+        .strip_suffix("_case")
+        .map_or_else(|| snake.clone(), str::to_owned)
+}
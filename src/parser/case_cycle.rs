@@ -0,0 +1,112 @@
+#![allow(dead_code, reason = "in lib but not in bin")]
+
+use super::case::Case;
+
+/// Default order of the cycle, used by [`Case::next`] and [`Case::prev`].
+const DEFAULT_ORDER: &[Case] = &[
+    Case::Snake,
+    Case::Camel,
+    Case::Pascal,
+    Case::Kebab,
+    Case::Constant,
+    Case::Dot,
+    Case::Sentence,
+    Case::Capitalised,
+    Case::CssVar,
+];
+
+/// Order in which to cycle through cases, for example to toggle an identifier in an editor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaseCycle {
+    /// Cases of the cycle, in order.
+    order: Vec<Case>,
+}
+
+impl CaseCycle {
+    /// Detects the current case of the input and converts it to the next case of the cycle.
+    ///
+    /// Cases that leave the input unchanged are skipped.
+    #[must_use]
+    pub fn cycle_next(&self, input: &str) -> String {
+        cycle(&self.order, input, true)
+    }
+
+    /// Detects the current case of the input and converts it to the previous case of the cycle.
+    ///
+    /// Cases that leave the input unchanged are skipped.
+    #[must_use]
+    pub fn cycle_prev(&self, input: &str) -> String {
+        cycle(&self.order, input, false)
+    }
+
+    /// Creates a cycle going through the given cases in order.
+    ///
+    /// Returns `None` if `order` is empty.
+    #[must_use]
+    pub fn new(order: Vec<Case>) -> Option<Self> {
+        (!order.is_empty()).then_some(Self { order })
+    }
+
+    /// Returns the case after the given one, or the first case if it isn't part of the cycle.
+    #[must_use]
+    pub fn next(&self, case: Case) -> Case {
+        step(&self.order, case, true)
+    }
+
+    /// Returns the cases of the cycle, in order.
+    #[must_use]
+    pub fn order(&self) -> &[Case] {
+        &self.order
+    }
+
+    /// Returns the case before the given one, or the last case if it isn't part of the cycle.
+    #[must_use]
+    pub fn prev(&self, case: Case) -> Case {
+        step(&self.order, case, false)
+    }
+}
+
+impl Default for CaseCycle {
+    /// Cycles through `snake_case`, `camelCase`, `PascalCase`, `kebab-case`, `CONSTANT_CASE`,
+    /// then the other cases.
+    fn default() -> Self {
+        Self {
+            order: DEFAULT_ORDER.to_vec(),
+        }
+    }
+}
+
+/// Converts the input to the case following its current case in `order`.
+///
+/// The current case is the first case of `order` the input is already in. Cases that leave the
+/// input unchanged are skipped, so that toggling an ambiguous input like `foo` always changes it.
+fn cycle(order: &[Case], input: &str, forward: bool) -> String {
+    let current = order.iter().copied().find(|case| case.matches(input));
+    following(order, current, forward)
+        .into_iter()
+        .map(|case| case.caseify(input))
+        .find(|output| output != input)
+        .unwrap_or_else(|| input.to_owned())
+}
+
+/// Returns the cases of `order`, starting just after `current` and wrapping around.
+///
+/// If `current` isn't part of `order`, the cases are returned from the start of the cycle.
+fn following(order: &[Case], current: Option<Case>, forward: bool) -> Vec<Case> {
+    let mut cases = order.to_vec();
+    if !forward {
+        cases.reverse();
+    }
+    if let Some(index) = current.and_then(|case| cases.iter().position(|other| *other == case)) {
+        cases.rotate_left(index.saturating_add(1));
+    }
+    cases
+}
+
+/// Returns the case just after `current` in `order`.
+fn step(order: &[Case], current: Case, forward: bool) -> Case {
+    following(order, Some(current), forward)
+        .first()
+        .copied()
+        .unwrap_or(current)
+}
@@ -8,6 +8,8 @@ pub trait Caseify {
     fn to_capitalised_case(&self) -> String;
     /// Converts the string to `CONSTANT_CASE`.
     fn to_constant_case(&self) -> String;
+    /// Converts the string to a `--css-var` custom property name.
+    fn to_css_var_case(&self) -> String;
     /// Converts the string to `dot.case`.
     fn to_dot_case(&self) -> String;
     /// Converts the string to `kebab-case`.
@@ -49,6 +51,15 @@ impl Caseify for str {
         )
     }
 
+    fn to_css_var_case(&self) -> String {
+        let kebab = self.to_kebab_case();
+        if kebab.is_empty() {
+            kebab
+        } else {
+            format!("--{kebab}")
+        }
+    }
+
     fn to_dot_case(&self) -> String {
         to_new_case(
             self,
@@ -104,15 +115,19 @@ impl Caseify for str {
 
 /// Pushes the lowercase representation of the given character to the output string.
 fn push_lower(output: &mut String, ch: char) {
-    for byte in ch.to_lowercase() {
-        output.push(byte);
+    if ch.is_ascii() {
+        output.push(ch.to_ascii_lowercase());
+    } else {
+        output.extend(ch.to_lowercase());
     }
 }
 
 /// Pushes the uppercase representation of the given character to the output string.
 fn push_upper(output: &mut String, ch: char) {
-    for byte in ch.to_uppercase() {
-        output.push(byte);
+    if ch.is_ascii() {
+        output.push(ch.to_ascii_uppercase());
+    } else {
+        output.extend(ch.to_uppercase());
     }
 }
 
@@ -129,6 +144,7 @@ fn handle_func<F: Fn(&mut String, char), G: Fn(&mut String, char)>(
 ) -> CharType {
     let new = CharType::from(prev);
     match (&old, &new) {
+        (CharType::None, CharType::Symbol) => return CharType::None,
         (CharType::None, _) => {
             if is_first_capitalised {
                 push_upper(output, prev);
@@ -5,7 +5,7 @@ pub enum CharType {
     CapitalOrNumber,
     /// The character is a lowercase letter.
     Lowercase,
-    /// We are reading before the first character of the string.
+    /// We are reading before the first alphanumeric character of the string.
     None,
     /// The character is a symbol.
     Symbol,
@@ -13,6 +13,15 @@ pub enum CharType {
 
 impl From<char> for CharType {
     fn from(value: char) -> Self {
+        if value.is_ascii() {
+            return if value.is_ascii_lowercase() {
+                Self::Lowercase
+            } else if value.is_ascii_alphanumeric() {
+                Self::CapitalOrNumber
+            } else {
+                Self::Symbol
+            };
+        }
         if !value.is_alphanumeric() {
             Self::Symbol
         } else if value.is_lowercase() {
@@ -0,0 +1,390 @@
+#![allow(dead_code, reason = "in lib but not in bin")]
+#![allow(clippy::single_call_fn, reason = "in bin but not in lib")]
+
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use super::case::{Case, normalise};
+
+/// Naming convention of a programming language, telling the case of each kind of identifier.
+///
+/// The cases are those of the official style guides, like PEP 8 for Python or the API guidelines
+/// for Rust.
+#[expect(clippy::min_ident_chars, reason = "`C` is the name of the language")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Convention {
+    /// C: `snake_case` types, functions and variables, and `CONSTANT_CASE` macros, constants and
+    /// enum constants.
+    C,
+    /// C#: `camelCase` locals and parameters, `PascalCase` for everything else.
+    CSharp,
+    /// Go: `PascalCase` for exported names and `camelCase` for unexported ones, and lowercase
+    /// package and file names.
+    Go,
+    /// Java: `PascalCase` types, `camelCase` methods, fields and locals, `CONSTANT_CASE`
+    /// constants and enum constants, and `dot.case` packages.
+    Java,
+    /// JavaScript and TypeScript: `PascalCase` types, `camelCase` functions, fields and locals,
+    /// `CONSTANT_CASE` constants, and `kebab-case` files and modules.
+    JavaScript,
+    /// Kotlin: same as Java.
+    Kotlin,
+    /// Python (PEP 8): `PascalCase` classes, `CONSTANT_CASE` constants and enum members, and
+    /// `snake_case` for everything else.
+    Python,
+    /// Rust: `PascalCase` types and enum variants, `CONSTANT_CASE` constants and statics, and
+    /// `snake_case` for everything else.
+    Rust,
+    /// SQL: `snake_case` for everything.
+    Sql,
+    /// Swift: `PascalCase` types and modules, and `camelCase` for everything else, enum cases
+    /// and constants included.
+    Swift,
+}
+
+impl Convention {
+    /// List of all the conventions, in alphabetical order.
+    pub const ALL: &[Self] = &[
+        Self::C,
+        Self::CSharp,
+        Self::Go,
+        Self::Java,
+        Self::JavaScript,
+        Self::Kotlin,
+        Self::Python,
+        Self::Rust,
+        Self::Sql,
+        Self::Swift,
+    ];
+
+    /// Returns the aliases accepted when parsing the convention, on top of its name.
+    ///
+    /// The aliases are normalised with [`normalise`].
+    const fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::CSharp => &["csharp", "cs"],
+            Self::Go => &["golang"],
+            Self::C | Self::Java | Self::Sql | Self::Swift => &[],
+            Self::JavaScript => &["javascript", "js", "typescript", "type_script", "ts"],
+            Self::Kotlin => &["kt"],
+            Self::Python => &["py", "pep8", "pep_8"],
+            Self::Rust => &["rs"],
+        }
+    }
+
+    /// Returns the case of the kind of identifiers in the convention.
+    ///
+    /// For Go, this is the case of the exported names: see [`Convention::unexported_case`].
+    #[must_use]
+    pub const fn case(self, kind: IdentKind) -> Case {
+        match (self, kind) {
+            (
+                Self::C | Self::Java | Self::JavaScript | Self::Kotlin | Self::Python | Self::Rust,
+                IdentKind::Constant,
+            )
+            | (Self::C | Self::Java | Self::Kotlin | Self::Python, IdentKind::EnumVariant) => {
+                Case::Constant
+            }
+            (Self::C | Self::Sql, _)
+            | (
+                Self::Python | Self::Rust,
+                IdentKind::Field
+                | IdentKind::FileName
+                | IdentKind::Function
+                | IdentKind::Local
+                | IdentKind::Module,
+            )
+            | (Self::Go, IdentKind::FileName | IdentKind::Module) => Case::Snake,
+            (Self::Java | Self::Kotlin, IdentKind::Module) => Case::Dot,
+            (Self::JavaScript, IdentKind::FileName | IdentKind::Module) => Case::Kebab,
+            (
+                Self::CSharp
+                | Self::Go
+                | Self::Java
+                | Self::JavaScript
+                | Self::Kotlin
+                | Self::Swift,
+                IdentKind::Local,
+            )
+            | (
+                Self::Java | Self::JavaScript | Self::Kotlin | Self::Swift,
+                IdentKind::Field | IdentKind::Function,
+            )
+            | (Self::Swift, IdentKind::Constant | IdentKind::EnumVariant) => Case::Camel,
+            _ => Case::Pascal,
+        }
+    }
+
+    /// Returns the case the identifier is converted to by [`Convention::convert`].
+    ///
+    /// This is the case of its kind, or for Go, the case of its kind when it isn't exported if the
+    /// name starts in lowercase.
+    #[must_use]
+    pub fn case_for(self, kind: IdentKind, name: &str) -> Case {
+        let is_exported = name
+            .trim_start_matches(['_', '$'])
+            .chars()
+            .next()
+            .is_some_and(char::is_uppercase);
+        if is_exported {
+            self.case(kind)
+        } else {
+            self.unexported_case(kind)
+        }
+    }
+
+    /// Converts the identifier to the case of its kind in the convention.
+    ///
+    /// The leading and trailing underscores of the name are kept, like the `_private` names of
+    /// Python. In Go, names starting in lowercase stay unexported, package names are converted to
+    /// lowercase without underscores, and initialisms stay in capitals, like in `ServeHTTP` or
+    /// `userID`.
+    #[must_use]
+    pub fn convert(self, kind: IdentKind, name: &str) -> String {
+        let converted = recase_keeping_affixes(self.case_for(kind, name), name);
+        match self {
+            Self::Go if kind == IdentKind::Module => converted.replace('_', ""),
+            Self::Go if collapse_capitals(name) == converted => name.to_owned(),
+            Self::C
+            | Self::CSharp
+            | Self::Go
+            | Self::Java
+            | Self::JavaScript
+            | Self::Kotlin
+            | Self::Python
+            | Self::Rust
+            | Self::Sql
+            | Self::Swift => converted,
+        }
+    }
+
+    /// Returns an iterator over all the conventions, in alphabetical order.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Returns the name of the convention, as written in the enum.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::C => "C",
+            Self::CSharp => "CSharp",
+            Self::Go => "Go",
+            Self::Java => "Java",
+            Self::JavaScript => "JavaScript",
+            Self::Kotlin => "Kotlin",
+            Self::Python => "Python",
+            Self::Rust => "Rust",
+            Self::Sql => "Sql",
+            Self::Swift => "Swift",
+        }
+    }
+
+    /// Returns the case of the kind of identifiers when they aren't exported.
+    ///
+    /// Only Go tells exported names apart by their case: its unexported types, functions,
+    /// fields and constants are in `camelCase`. For the other conventions, this is the same as
+    /// [`Convention::case`].
+    #[must_use]
+    pub const fn unexported_case(self, kind: IdentKind) -> Case {
+        match (self, kind) {
+            (
+                Self::Go,
+                IdentKind::Constant
+                | IdentKind::EnumVariant
+                | IdentKind::Field
+                | IdentKind::Function
+                | IdentKind::Type,
+            ) => Case::Camel,
+            _ => self.case(kind),
+        }
+    }
+}
+
+impl fmt::Display for Convention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Convention {
+    type Err = ParseConventionError;
+
+    /// Parses a convention from the name of its language (`Rust`), in any case (`rust`), or from
+    /// one of its aliases (`rs`, `pep8`, `c#`).
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        if name.eq_ignore_ascii_case("c#") {
+            return Ok(Self::CSharp);
+        }
+        let normalised = normalise(name);
+        Self::iter()
+            .find(|convention| {
+                normalise(convention.name()) == normalised
+                    || convention.aliases().contains(&&*normalised)
+            })
+            .ok_or_else(|| ParseConventionError(name.to_owned()))
+    }
+}
+
+/// Kind of the identifiers of source code, that conventions give different cases to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum IdentKind {
+    /// Constants and statics, and macros in C.
+    Constant,
+    /// Variants of enums, like `Ordering::Less`.
+    EnumVariant,
+    /// Fields of structs, classes and objects, and properties.
+    Field,
+    /// Names of source files, without their extension.
+    FileName,
+    /// Functions and methods.
+    Function,
+    /// Local and global variables, and parameters.
+    Local,
+    /// Modules, packages and namespaces.
+    Module,
+    /// Structs, enums, classes, interfaces, traits and type aliases.
+    Type,
+}
+
+impl IdentKind {
+    /// List of all the kinds of identifiers, in alphabetical order.
+    pub const ALL: &[Self] = &[
+        Self::Constant,
+        Self::EnumVariant,
+        Self::Field,
+        Self::FileName,
+        Self::Function,
+        Self::Local,
+        Self::Module,
+        Self::Type,
+    ];
+
+    /// Returns the aliases accepted when parsing the kind, on top of its name.
+    ///
+    /// The aliases are normalised with [`normalise`].
+    const fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::Constant => &["const", "static"],
+            Self::EnumVariant => &["variant"],
+            Self::Field => &["property"],
+            Self::FileName => &["file"],
+            Self::Function => &["fn", "method"],
+            Self::Local => &["var", "variable", "parameter"],
+            Self::Module => &["mod", "namespace", "package"],
+            Self::Type => &["class", "struct"],
+        }
+    }
+
+    /// Returns an iterator over all the kinds of identifiers, in alphabetical order.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Returns the name of the kind, in lowercase words.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Constant => "constant",
+            Self::EnumVariant => "enum variant",
+            Self::Field => "field",
+            Self::FileName => "file name",
+            Self::Function => "function",
+            Self::Local => "local",
+            Self::Module => "module",
+            Self::Type => "type",
+        }
+    }
+}
+
+impl fmt::Display for IdentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for IdentKind {
+    type Err = ParseIdentKindError;
+
+    /// Parses a kind from its name, in any case (`enum variant`, `enum-variant`, `EnumVariant`),
+    /// or from one of its aliases (`fn`, `variable`, `package`).
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let normalised = normalise(name);
+        Self::iter()
+            .find(|kind| {
+                normalise(kind.name()) == normalised || kind.aliases().contains(&&*normalised)
+            })
+            .ok_or_else(|| ParseIdentKindError(name.to_owned()))
+    }
+}
+
+/// Error returned when parsing a [`Convention`] from an unknown name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseConventionError(String);
+
+impl fmt::Display for ParseConventionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} isn't a valid convention", self.0)
+    }
+}
+
+#[expect(clippy::missing_trait_methods, reason = "no source error")]
+impl Error for ParseConventionError {}
+
+/// Error returned when parsing an [`IdentKind`] from an unknown name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseIdentKindError(String);
+
+impl fmt::Display for ParseIdentKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} isn't a valid identifier kind", self.0)
+    }
+}
+
+#[expect(clippy::missing_trait_methods, reason = "no source error")]
+impl Error for ParseIdentKindError {}
+
+/// Lowercases the capitals that follow another one, unless they start a word, like `ServeHTTP`
+/// to `ServeHttp` and `HTTPServer` to `HttpServer`.
+fn collapse_capitals(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut collapsed = String::with_capacity(name.len());
+    for (index, &ch) in chars.iter().enumerate() {
+        let after_capital = index
+            .checked_sub(1)
+            .and_then(|previous| chars.get(previous))
+            .is_some_and(|previous| previous.is_uppercase());
+        let starts_word = chars
+            .get(index.saturating_add(1))
+            .is_some_and(|next| next.is_lowercase());
+        if ch.is_uppercase() && after_capital && !starts_word {
+            collapsed.extend(ch.to_lowercase());
+        } else {
+            collapsed.push(ch);
+        }
+    }
+    collapsed
+}
+
+/// Converts the identifier to the case, keeping its leading underscores and dollars and its
+/// trailing underscores, or returns it unchanged if it has nothing to convert.
+pub fn recase_keeping_affixes(case: Case, name: &str) -> String {
+    let core = name.trim_start_matches(['_', '$']).trim_end_matches('_');
+    let converted = case.caseify(core);
+    if converted.is_empty() {
+        return name.to_owned();
+    }
+    let start = name
+        .len()
+        .saturating_sub(name.trim_start_matches(['_', '$']).len());
+    let prefix = name.get(..start).unwrap_or_default();
+    let suffix = name
+        .get(start.saturating_add(core.len())..)
+        .unwrap_or_default();
+    let mut recased = String::with_capacity(name.len());
+    recased.push_str(prefix);
+    recased.push_str(&converted);
+    recased.push_str(suffix);
+    recased
+}
@@ -3,12 +3,20 @@
     clippy::pattern_type_mismatch,
     clippy::missing_inline_in_public_items,
     clippy::mod_module_files,
+    clippy::question_mark_used,
     reason = "chosen style"
 )]
 
-/// Defines the [`Case`] struct to list the supported cases.
+/// Defines the [`Bem`](bem::Bem) struct to build and parse BEM class names.
+pub mod bem;
+/// Defines the [`Case`](case::Case) struct to list the supported cases.
 pub mod case;
-/// Defines the [`Caseify`] trait to transform an input to the wanted case.
+/// Defines the [`CaseCycle`](case_cycle::CaseCycle) struct to cycle through cases.
+pub mod case_cycle;
+/// Defines the [`Caseify`](caseify::Caseify) trait to transform an input to the wanted case.
 pub mod caseify;
 /// Internal state for parsing and casifying
 mod char_type;
+/// Defines the [`Convention`](convention::Convention) enum to give each kind of identifier the case
+/// of a language.
+pub mod convention;
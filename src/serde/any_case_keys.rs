@@ -0,0 +1,414 @@
+use core::fmt;
+
+use ::serde::Deserialize;
+use ::serde::de::value::{StringDeserializer, U64Deserializer};
+use ::serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+
+use crate::Caseify as _;
+
+/// Forwards methods without arguments to the inner deserializer, wrapping the visitor.
+macro_rules! forward_deserialize {
+    ($($method:ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                self.0.$method(AnyCaseVisitor::new(visitor, None))
+            }
+        )*
+    };
+}
+
+/// Forwards the visit of values without nested keys to the inner visitor.
+macro_rules! forward_visit {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method<E: de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+/// Deserialises the wrapped value, accepting the fields of its structs in any case.
+///
+/// A key matches a field if both are equal once converted to `snake_case`, so `first-name`,
+/// `firstName` and `FIRST_NAME` all fill the `first_name` field. Nested structs are handled as
+/// well. Keys of maps are left as is, since there is no expected key to match them against.
+///
+/// ```
+/// use caseify::serde::AnyCaseKeys;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct User {
+///     first_name: String,
+/// }
+///
+/// let AnyCaseKeys(user) =
+///     serde_json::from_str::<AnyCaseKeys<User>>(r#"{"firstName": "Ada"}"#).unwrap();
+/// assert_eq!(user.first_name, "Ada");
+/// ```
+#[expect(clippy::exhaustive_structs, reason = "built by the user")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnyCaseKeys<T>(pub T);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for AnyCaseKeys<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(AnyCaseDeserializer(deserializer)).map(Self)
+    }
+}
+
+/// Wrapper around a deserializer that accepts the fields of structs in any case.
+///
+/// See [`AnyCaseKeys`] for how keys are matched against the fields.
+#[expect(clippy::exhaustive_structs, reason = "built by the user")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnyCaseDeserializer<D>(pub D);
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for AnyCaseDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any,
+        deserialize_bool,
+        deserialize_byte_buf,
+        deserialize_bytes,
+        deserialize_char,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_identifier,
+        deserialize_ignored_any,
+        deserialize_map,
+        deserialize_option,
+        deserialize_seq,
+        deserialize_str,
+        deserialize_string,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_unit
+    );
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_enum(name, variants, AnyCaseVisitor::new(visitor, None))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_newtype_struct(name, AnyCaseVisitor::new(visitor, None))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_struct(name, fields, AnyCaseVisitor::new(visitor, Some(fields)))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_tuple(len, AnyCaseVisitor::new(visitor, None))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_tuple_struct(name, len, AnyCaseVisitor::new(visitor, None))
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_unit_struct(name, AnyCaseVisitor::new(visitor, None))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+/// Wrapper around the accessors given to a visitor, that wraps the nested values.
+struct AnyCaseAccess<A> {
+    /// Expected fields, if the accessor is reading a struct.
+    fields: Option<&'static [&'static str]>,
+    /// Wrapped accessor.
+    inner: A,
+}
+
+impl<A> AnyCaseAccess<A> {
+    /// Wraps an accessor.
+    const fn new(inner: A, fields: Option<&'static [&'static str]>) -> Self {
+        Self { fields, inner }
+    }
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for AnyCaseAccess<A> {
+    type Error = A::Error;
+    type Variant = AnyCaseAccess<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, AnyCaseAccess::new(variant, None)))
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for AnyCaseAccess<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.fields {
+            Some(fields) => self.inner.next_key_seed(FieldSeed { fields, seed }),
+            None => self.inner.next_key_seed(AnyCaseSeed(seed)),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner.next_value_seed(AnyCaseSeed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for AnyCaseAccess<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.inner.next_element_seed(AnyCaseSeed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for AnyCaseAccess<A> {
+    type Error = A::Error;
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        self.inner.newtype_variant_seed(AnyCaseSeed(seed))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner
+            .struct_variant(fields, AnyCaseVisitor::new(visitor, Some(fields)))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner
+            .tuple_variant(len, AnyCaseVisitor::new(visitor, None))
+    }
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+}
+
+/// Wrapper around a seed, that deserialises the value with an [`AnyCaseDeserializer`].
+struct AnyCaseSeed<S>(S);
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for AnyCaseSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.deserialize(AnyCaseDeserializer(deserializer))
+    }
+}
+
+/// Wrapper around a visitor, that wraps the accessors and deserializers it is given.
+struct AnyCaseVisitor<V> {
+    /// Expected fields, if the visitor is reading a struct.
+    fields: Option<&'static [&'static str]>,
+    /// Wrapped visitor.
+    inner: V,
+}
+
+impl<V> AnyCaseVisitor<V> {
+    /// Wraps a visitor.
+    const fn new(inner: V, fields: Option<&'static [&'static str]>) -> Self {
+        Self { fields, inner }
+    }
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for AnyCaseVisitor<V> {
+    type Value = V::Value;
+
+    forward_visit!(
+        visit_bool: bool,
+        visit_borrowed_bytes: &'de [u8],
+        visit_borrowed_str: &'de str,
+        visit_byte_buf: Vec<u8>,
+        visit_bytes: &[u8],
+        visit_char: char,
+        visit_f32: f32,
+        visit_f64: f64,
+        visit_i8: i8,
+        visit_i16: i16,
+        visit_i32: i32,
+        visit_i64: i64,
+        visit_i128: i128,
+        visit_str: &str,
+        visit_string: String,
+        visit_u8: u8,
+        visit_u16: u16,
+        visit_u32: u32,
+        visit_u64: u64,
+        visit_u128: u128
+    );
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(AnyCaseAccess::new(data, None))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(AnyCaseAccess::new(map, self.fields))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(AnyCaseDeserializer(deserializer))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_seq(AnyCaseAccess::new(seq, None))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(AnyCaseDeserializer(deserializer))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+}
+
+/// Seed of a struct key, that replaces the key by the field it matches.
+struct FieldSeed<S> {
+    /// Expected fields of the struct.
+    fields: &'static [&'static str],
+    /// Wrapped seed, that deserialises the field.
+    seed: S,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for FieldSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        match deserializer.deserialize_identifier(KeyVisitor)? {
+            Key::Index(index) => self.seed.deserialize(U64Deserializer::new(index)),
+            Key::Name(name) => {
+                let normalised = name.to_snake_case();
+                let field = self
+                    .fields
+                    .iter()
+                    .find(|field| field.to_snake_case() == normalised)
+                    .map_or(name, |field| (*field).to_owned());
+                self.seed.deserialize(StringDeserializer::new(field))
+            }
+        }
+    }
+}
+
+/// Key of a struct, as read by the wrapped deserializer.
+enum Key {
+    /// The field is given by its index, in non self-describing formats.
+    Index(u64),
+    /// The field is given by its name, in any case.
+    Name(String),
+}
+
+/// Visitor to read the key of a struct.
+struct KeyVisitor;
+
+impl Visitor<'_> for KeyVisitor {
+    type Value = Key;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a field name")
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        String::from_utf8(value.to_vec())
+            .map(Key::Name)
+            .map_err(|_err| E::invalid_value(de::Unexpected::Bytes(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Key::Name(value.to_owned()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Key::Name(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Key::Index(value))
+    }
+}
@@ -0,0 +1,36 @@
+use core::fmt;
+
+use ::serde::de::{self, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Case;
+
+/// Visitor to deserialise a [`Case`] from its name or one of its aliases.
+struct CaseVisitor;
+
+impl Visitor<'_> for CaseVisitor {
+    type Value = Case;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("the name of a case")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Case {
+    /// Deserialises a case from a string, accepting the same names and aliases as
+    /// [`FromStr`](core::str::FromStr).
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(CaseVisitor)
+    }
+}
+
+impl Serialize for Case {
+    /// Serialises the case as its name (e.g. `"Snake"`), which is stable across versions.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
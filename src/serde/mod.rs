@@ -0,0 +1,27 @@
+#![allow(
+    clippy::implicit_return,
+    clippy::missing_inline_in_public_items,
+    clippy::mod_module_files,
+    clippy::question_mark_used,
+    reason = "chosen style"
+)]
+#![allow(
+    clippy::missing_trait_methods,
+    clippy::renamed_function_params,
+    reason = "serde traits have many provided methods with short parameter names"
+)]
+#![allow(
+    clippy::arbitrary_source_item_ordering,
+    reason = "forwarded trait methods are grouped in macros"
+)]
+
+/// Defines the [`AnyCaseKeys`] wrapper to deserialise structs with keys in any case.
+mod any_case_keys;
+/// Implements [`Serialize`](::serde::Serialize) and [`Deserialize`](::serde::Deserialize) for
+/// [`Case`](crate::Case).
+mod case;
+/// Defines the [`RenameKeys`] wrapper to serialise keys in a case chosen at runtime.
+mod rename_keys;
+
+pub use any_case_keys::{AnyCaseDeserializer, AnyCaseKeys};
+pub use rename_keys::RenameKeys;
@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use ::serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use ::serde::{Serialize, Serializer};
+
+use crate::Case;
+
+/// Forwards methods with a single value to the inner serializer.
+macro_rules! forward {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+/// Renamed struct fields, leaked once each to satisfy the `'static` bound of [`SerializeStruct`].
+///
+/// Field names are `'static` themselves, so the cache is bounded by the number of fields in the
+/// program times the number of cases.
+static RENAMED_FIELDS: LazyLock<Mutex<HashMap<(Case, &str), &str>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Serialises the wrapped value with the keys of its structs and maps converted to the given
+/// [`Case`], at runtime.
+///
+/// Nested structs and maps are renamed as well, but string values and enum variants are left
+/// untouched.
+///
+/// ```
+/// use caseify::Case;
+/// use caseify::serde::RenameKeys;
+/// use std::collections::BTreeMap;
+///
+/// let map = BTreeMap::from([("firstName", 1), ("last_name", 2)]);
+/// let json = serde_json::to_string(&RenameKeys(Case::Kebab, &map)).unwrap();
+/// assert_eq!(json, r#"{"first-name":1,"last-name":2}"#);
+/// ```
+#[expect(clippy::exhaustive_structs, reason = "built by the user")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RenameKeys<T>(pub Case, pub T);
+
+impl<T: Serialize> Serialize for RenameKeys<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.1.serialize(Renamer::new(self.0, serializer, false))
+    }
+}
+
+/// Map key, serialised with its strings converted to the given [`Case`].
+struct Key<'key, T: ?Sized>(Case, &'key T);
+
+impl<T: Serialize + ?Sized> Serialize for Key<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.1.serialize(Renamer::new(self.0, serializer, true))
+    }
+}
+
+/// Wrapper around a serializer, or one of its compound serializers, that renames keys.
+struct Renamer<S> {
+    /// Case to convert the keys to.
+    case: Case,
+    /// Wrapped serializer.
+    inner: S,
+    /// Whether the value being serialised is a map key, in which case strings are renamed.
+    is_key: bool,
+}
+
+impl<S> Renamer<S> {
+    /// Wraps a serializer.
+    const fn new(case: Case, inner: S, is_key: bool) -> Self {
+        Self {
+            case,
+            inner,
+            is_key,
+        }
+    }
+}
+
+impl<S: Serializer> Serializer for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+    type SerializeMap = Renamer<S::SerializeMap>;
+    type SerializeSeq = Renamer<S::SerializeSeq>;
+    type SerializeStruct = Renamer<S::SerializeStruct>;
+    type SerializeStructVariant = Renamer<S::SerializeStructVariant>;
+    type SerializeTuple = Renamer<S::SerializeTuple>;
+    type SerializeTupleStruct = Renamer<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Renamer<S::SerializeTupleVariant>;
+
+    forward!(
+        serialize_bool: bool,
+        serialize_bytes: &[u8],
+        serialize_char: char,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_unit_struct: &'static str
+    );
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let map = self.inner.serialize_map(len)?;
+        Ok(Renamer::new(self.case, map, false))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        if self.is_key {
+            self.inner
+                .serialize_newtype_struct(name, &Key(self.case, value))
+        } else {
+            self.inner
+                .serialize_newtype_struct(name, &RenameKeys(self.case, value))
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &RenameKeys(self.case, value),
+        )
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let seq = self.inner.serialize_seq(len)?;
+        Ok(Renamer::new(self.case, seq, false))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        if self.is_key {
+            self.inner.serialize_some(&Key(self.case, value))
+        } else {
+            self.inner.serialize_some(&RenameKeys(self.case, value))
+        }
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Self::Ok, Self::Error> {
+        if self.is_key {
+            self.inner.serialize_str(&self.case.caseify(value))
+        } else {
+            self.inner.serialize_str(value)
+        }
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let compound = self.inner.serialize_struct(name, len)?;
+        Ok(Renamer::new(self.case, compound, false))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let compound = self
+            .inner
+            .serialize_struct_variant(name, variant_index, variant, len)?;
+        Ok(Renamer::new(self.case, compound, false))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        let compound = self.inner.serialize_tuple(len)?;
+        Ok(Renamer::new(self.case, compound, false))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let compound = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(Renamer::new(self.case, compound, false))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let compound = self
+            .inner
+            .serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(Renamer::new(self.case, compound, false))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+}
+
+impl<S: SerializeMap> SerializeMap for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_key(&Key(self.case, key))
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_value(&RenameKeys(self.case, value))
+    }
+}
+
+impl<S: SerializeSeq> SerializeSeq for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_element(&RenameKeys(self.case, value))
+    }
+}
+
+impl<S: SerializeStruct> SerializeStruct for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.inner
+            .serialize_field(rename_field(self.case, key), &RenameKeys(self.case, value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(rename_field(self.case, key))
+    }
+}
+
+impl<S: SerializeStructVariant> SerializeStructVariant for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.inner
+            .serialize_field(rename_field(self.case, key), &RenameKeys(self.case, value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(rename_field(self.case, key))
+    }
+}
+
+impl<S: SerializeTuple> SerializeTuple for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_element(&RenameKeys(self.case, value))
+    }
+}
+
+impl<S: SerializeTupleStruct> SerializeTupleStruct for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_field(&RenameKeys(self.case, value))
+    }
+}
+
+impl<S: SerializeTupleVariant> SerializeTupleVariant for Renamer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_field(&RenameKeys(self.case, value))
+    }
+}
+
+/// Converts a struct field to the given case.
+fn rename_field(case: Case, field: &'static str) -> &'static str {
+    let mut fields = RENAMED_FIELDS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    fields.entry((case, field)).or_insert_with(|| {
+        let renamed = case.caseify(field);
+        if renamed == field {
+            field
+        } else {
+            Box::leak(renamed.into_boxed_str())
+        }
+    })
+}
@@ -0,0 +1,51 @@
+use caseify::Bem;
+
+#[test]
+fn build() {
+    assert_eq!(
+        Bem::new("navBar", Some("menu_item"), Some("IsActive")).to_string(),
+        "nav-bar__menu-item--is-active"
+    );
+    assert_eq!(
+        Bem::new("Card", Some("title"), None).to_string(),
+        "card__title"
+    );
+    assert_eq!(
+        Bem::new("Card", None, Some("large")).to_string(),
+        "card--large"
+    );
+    assert_eq!(Bem::new("Card", Some(""), Some("")).to_string(), "card");
+}
+
+#[test]
+fn parse() {
+    let bem = Bem::parse("nav-bar__menu-item--is-active").unwrap();
+    assert_eq!(
+        bem,
+        Bem::new("nav-bar", Some("menu-item"), Some("is-active"))
+    );
+    assert_eq!(
+        bem.words(),
+        [
+            vec!["nav", "bar"],
+            vec!["menu", "item"],
+            vec!["is", "active"]
+        ]
+    );
+
+    let bem = Bem::parse("card--large").unwrap();
+    assert_eq!(bem.element, None);
+    assert_eq!(bem.modifier.as_deref(), Some("large"));
+
+    assert_eq!(Bem::parse("card").unwrap().to_string(), "card");
+    assert_eq!(Bem::parse("__title"), None);
+    assert_eq!(Bem::parse("card__"), None);
+    assert_eq!(Bem::parse("card__title--"), None);
+    assert_eq!(Bem::parse("card__--large"), None);
+    assert_eq!(Bem::parse("card____title"), None);
+    assert_eq!(Bem::parse("card__title__text"), None);
+    assert_eq!(Bem::parse("card---large"), None);
+    assert_eq!(Bem::parse("card--large--dark"), None);
+    assert_eq!(Bem::parse("Card__Title"), None);
+    assert_eq!(Bem::parse("card__title_text"), None);
+}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use caseify::Case;
+
+#[test]
+fn all() {
+    assert_eq!(Case::ALL.len(), 9);
+    assert_eq!(Case::iter().collect::<Vec<_>>(), Case::ALL);
+    assert!(Case::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(Case::iter().collect::<HashSet<_>>().len(), Case::ALL.len());
+}
+
+#[test]
+fn display_from_str() {
+    for case in Case::iter() {
+        assert_eq!(case.to_string().parse::<Case>(), Ok(case));
+    }
+    assert_eq!(Case::Snake.to_string(), "Snake");
+    assert_eq!(Case::CssVar.to_string(), "CssVar");
+}
+
+#[test]
+fn aliases() {
+    for name in [
+        "Snake",
+        "snake",
+        "SNAKE",
+        "snake_case",
+        "snake-case",
+        "SnakeCase",
+    ] {
+        assert_eq!(name.parse::<Case>(), Ok(Case::Snake), "{name}");
+    }
+    for name in ["SCREAMING_SNAKE", "screaming-snake-case", "CONSTANT_CASE"] {
+        assert_eq!(name.parse::<Case>(), Ok(Case::Constant), "{name}");
+    }
+    for name in ["camelCase", "lower-camel", "lowerCamelCase"] {
+        assert_eq!(name.parse::<Case>(), Ok(Case::Camel), "{name}");
+    }
+    for name in ["css-var", "--css-var", "css_variable"] {
+        assert_eq!(name.parse::<Case>(), Ok(Case::CssVar), "{name}");
+    }
+    assert_eq!("Title Case".parse::<Case>(), Ok(Case::Capitalised));
+    assert_eq!("Sentence case".parse::<Case>(), Ok(Case::Sentence));
+    assert_eq!("dot.case".parse::<Case>(), Ok(Case::Dot));
+    assert_eq!("PascalCase".parse::<Case>(), Ok(Case::Pascal));
+    assert_eq!("kebab-case".parse::<Case>(), Ok(Case::Kebab));
+}
+
+#[test]
+fn invalid() {
+    let err = "blob".parse::<Case>().unwrap_err();
+    assert_eq!(err.to_string(), "blob isn't a valid case");
+    assert!("".parse::<Case>().is_err());
+    assert!("case".parse::<Case>().is_err());
+}
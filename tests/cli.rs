@@ -1,6 +1,13 @@
-use std::io::Write;
-use std::process::{Command, Stdio};
-use std::str;
+use std::io::{BufRead as _, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{self, Command, Output, Stdio};
+use std::{env, fs, str, thread};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("caseify-{name}-{}", process::id()));
+    fs::create_dir_all(&dir).expect("Failed to create temporary directory");
+    dir
+}
 
 #[test]
 fn stdin() {
@@ -33,3 +40,682 @@ fn arg() {
     let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
     assert_eq!(output_str, "helloWorld\n");
 }
+
+#[test]
+fn options() {
+    let child = Command::new("./target/debug/caseify")
+        .args(["--snake", "--", "--Hello, World!"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+
+    let output = child.wait_with_output().expect("Failed to read stdout");
+    let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
+    assert_eq!(output_str, "hello_world\n");
+}
+
+#[test]
+fn multiple_values() {
+    let child = Command::new("./target/debug/caseify")
+        .args(["Snake", "helloWorld", "FooBar"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+
+    let output = child.wait_with_output().expect("Failed to read stdout");
+    let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
+    assert_eq!(output_str, "hello_world\nfoo_bar\n");
+}
+
+#[test]
+fn join() {
+    let child = Command::new("./target/debug/caseify")
+        .args(["Snake", "--join", "hello", "World"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+
+    let output = child.wait_with_output().expect("Failed to read stdout");
+    let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
+    assert_eq!(output_str, "hello_world\n");
+}
+
+#[test]
+fn files() {
+    let dir = temp_dir("files");
+    let first = dir.join("first.txt");
+    let second = dir.join("second.txt");
+    fs::write(&first, "helloWorld\nFooBar\n").unwrap();
+    fs::write(&second, "some value").unwrap();
+
+    let child = Command::new("./target/debug/caseify")
+        .arg("Snake")
+        .arg("-f")
+        .args([&first, &second])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+
+    let output = child.wait_with_output().expect("Failed to read stdout");
+    let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
+    assert_eq!(output_str, "hello_world\nfoo_bar\nsome_value\n");
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn in_place() {
+    let dir = temp_dir("in-place");
+    let file = dir.join("names.txt");
+    fs::write(&file, "helloWorld\nFooBar\n").unwrap();
+
+    let status = Command::new("./target/debug/caseify")
+        .args(["--kebab", "--in-place=.bak"])
+        .arg(&file)
+        .status()
+        .expect("Failed to run process");
+
+    assert!(status.success());
+    assert_eq!(fs::read_to_string(&file).unwrap(), "hello-world\nfoo-bar\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("names.txt.bak")).unwrap(),
+        "helloWorld\nFooBar\n"
+    );
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn in_place_symlink() {
+    let dir = temp_dir("in-place-symlink");
+    let file = dir.join("names.txt");
+    let link = dir.join("link.txt");
+    fs::write(&file, "helloWorld\n").unwrap();
+    std::os::unix::fs::symlink(&file, &link).unwrap();
+
+    let status = Command::new("./target/debug/caseify")
+        .args(["--snake", "-i"])
+        .arg(&link)
+        .status()
+        .expect("Failed to run process");
+
+    assert!(status.success());
+    assert!(fs::symlink_metadata(&link).unwrap().is_symlink());
+    assert_eq!(fs::read_to_string(&file).unwrap(), "hello_world\n");
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn exit_codes() {
+    let help = Command::new("./target/debug/caseify")
+        .arg("--help")
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(help.status.code(), Some(0));
+    assert!(help.stderr.is_empty());
+    assert!(str::from_utf8(&help.stdout).unwrap().contains("Usage: "));
+
+    let invalid = Command::new("./target/debug/caseify")
+        .arg("blob")
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(invalid.status.code(), Some(64));
+    assert!(invalid.stdout.is_empty());
+}
+
+#[test]
+fn detect() {
+    let mut child = Command::new("./target/debug/caseify")
+        .args(["detect", "--porcelain"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+
+    {
+        let stdin = child.stdin.as_mut().expect("Failed to open stdin");
+        writeln!(stdin, "foo_bar\nFooBar").expect("Failed to write to stdin");
+    }
+
+    let output = child.wait_with_output().expect("Failed to read stdout");
+    let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
+    assert_eq!(output_str, "Snake\tfoo_bar\nPascal\tFooBar\n");
+}
+
+#[test]
+fn check() {
+    let dir = temp_dir("check");
+    let file = dir.join("keys.txt");
+    fs::write(&file, "foo_bar\nfooBar\nbaz\n").unwrap();
+
+    let output = Command::new("./target/debug/caseify")
+        .args(["check", "Snake", "--files"])
+        .arg(&file)
+        .output()
+        .expect("Failed to run process");
+
+    assert_eq!(output.status.code(), Some(1));
+    let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
+    assert_eq!(
+        output_str,
+        format!("{}:2: fooBar -> foo_bar\n", file.display())
+    );
+    fs::remove_dir_all(dir).unwrap();
+
+    let output = Command::new("./target/debug/caseify")
+        .args(["check", "Kebab", "foo_bar", "fooBar"])
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        str::from_utf8(&output.stdout).unwrap(),
+        "<args>:1: foo_bar -> foo-bar\n<args>:2: fooBar -> foo-bar\n"
+    );
+    assert!(
+        str::from_utf8(&output.stderr)
+            .unwrap()
+            .contains("2 values aren't in Kebab case")
+    );
+
+    let output = Command::new("./target/debug/caseify")
+        .args(["--kebab", "check"])
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(str::from_utf8(&output.stdout).unwrap(), "check\n");
+}
+
+#[test]
+fn rename() {
+    let dir = temp_dir("rename");
+    fs::create_dir_all(dir.join("Some Dir")).unwrap();
+    fs::write(dir.join("Some Dir/My File.txt"), "").unwrap();
+    fs::write(dir.join("Readme.md"), "").unwrap();
+
+    let dry_run = Command::new("./target/debug/caseify")
+        .args(["rename", "Snake", "--dry-run", "--recursive"])
+        .arg(dir.join("Some Dir"))
+        .output()
+        .expect("Failed to run process");
+    assert!(dry_run.status.success());
+    assert!(dir.join("Some Dir/My File.txt").exists());
+
+    let output = Command::new("./target/debug/caseify")
+        .args(["rename", "Snake", "-r"])
+        .args([dir.join("Some Dir"), dir.join("Readme.md")])
+        .output()
+        .expect("Failed to run process");
+    assert!(output.status.success());
+    assert!(dir.join("some_dir/my_file.txt").exists());
+    let names = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect::<Vec<_>>();
+    assert!(names.contains(&"readme.md".into()));
+    assert!(!names.contains(&"Readme.md".into()));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn rename_collisions() {
+    let dir = temp_dir("rename-collisions");
+    fs::write(dir.join("fooBar.txt"), "").unwrap();
+    fs::write(dir.join("FooBar.txt"), "").unwrap();
+    fs::write(dir.join("other.txt"), "").unwrap();
+
+    let output = Command::new("./target/debug/caseify")
+        .args(["rename", "Snake", "-r"])
+        .arg(&dir)
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(dir.join("fooBar.txt").exists());
+    assert!(dir.join("FooBar.txt").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn records() {
+    let dir = temp_dir("records");
+    let file = dir.join("names.txt");
+    fs::write(&file, "helloWorld\r\nFooBar").unwrap();
+
+    let status = Command::new("./target/debug/caseify")
+        .args(["--snake", "-i"])
+        .arg(&file)
+        .status()
+        .expect("Failed to run process");
+    assert!(status.success());
+    assert_eq!(fs::read_to_string(&file).unwrap(), "hello_world\r\nfoo_bar");
+
+    let mut child = Command::new("./target/debug/caseify")
+        .args(["--kebab", "-0"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+    {
+        let stdin = child.stdin.as_mut().expect("Failed to open stdin");
+        write!(stdin, "My\nFile\0otherFile\0").expect("Failed to write to stdin");
+    }
+    let output = child.wait_with_output().expect("Failed to read stdout");
+    let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
+    assert_eq!(output_str, "my-file\0other-file\0");
+    fs::remove_dir_all(dir).unwrap();
+}
+
+fn run_with_stdin(args: &[&str], input: &[u8]) -> (String, String) {
+    let output = output_with_stdin(args, input);
+    (
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+fn output_with_stdin(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new("./target/debug/caseify")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+    child
+        .stdin
+        .as_mut()
+        .expect("Failed to open stdin")
+        .write_all(input)
+        .expect("Failed to write to stdin");
+    child.wait_with_output().expect("Failed to read stdout")
+}
+
+#[test]
+fn invalid_utf8() {
+    let input = b"fooBar\nBad\xffValue\nbazQux\n";
+
+    let (lossy, warning) = run_with_stdin(&["Kebab", "--invalid=lossy"], input);
+    assert_eq!(lossy, "foo-bar\nbad-value\nbaz-qux\n");
+    assert!(warning.contains("<stdin>: line 2 isn't valid UTF-8, replacing the invalid bytes"));
+
+    let passed = output_with_stdin(&["Kebab", "--invalid", "pass"], input);
+    assert_eq!(passed.stdout, b"foo-bar\nBad\xffValue\nbaz-qux\n");
+    assert!(
+        String::from_utf8_lossy(&passed.stderr)
+            .contains("<stdin>: line 2 isn't valid UTF-8, leaving it unchanged")
+    );
+
+    let (error, message) = run_with_stdin(&["Kebab"], input);
+    assert_eq!(error, "foo-bar\n");
+    assert!(message.contains("line 2: invalid utf-8"));
+}
+
+#[test]
+fn broken_pipe() {
+    let mut child = Command::new("./target/debug/caseify")
+        .arg("Snake")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to start process");
+
+    let mut stdin = child.stdin.take().expect("Failed to open stdin");
+    let writer = thread::spawn(move || {
+        for _ in 0..100_000_u32 {
+            if writeln!(stdin, "helloWorld").is_err() {
+                break;
+            }
+        }
+    });
+    let mut first_line = String::new();
+    BufReader::new(child.stdout.take().expect("Failed to open stdout"))
+        .read_line(&mut first_line)
+        .expect("Failed to read stdout");
+    assert_eq!(first_line, "hello_world\n");
+
+    let output = child.wait_with_output().expect("Failed to wait");
+    writer.join().expect("Failed to write to stdin");
+    assert!(output.status.success());
+    assert_eq!(str::from_utf8(&output.stderr), Ok(""));
+}
+
+#[test]
+fn jobs() {
+    let dir = temp_dir("jobs");
+    let path = dir.join("columns.txt");
+    fs::write(&path, "fooBar\r\nHTTPServer\nsome value\n".repeat(100_000)).unwrap();
+
+    let convert = |args: &[&str]| {
+        let output = Command::new("./target/debug/caseify")
+            .args(args)
+            .arg(&path)
+            .output()
+            .expect("Failed to run process");
+        assert!(output.stderr.is_empty());
+        output.stdout
+    };
+    let parallel = convert(&["Snake", "--jobs", "3", "-f"]);
+    assert!(parallel == convert(&["Snake", "-f"]));
+    assert!(
+        parallel
+            == "foo_bar\r\nhttp_server\nsome_value\n"
+                .repeat(100_000)
+                .into_bytes()
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn fields() {
+    let dir = temp_dir("fields");
+    let path = dir.join("data.csv");
+    fs::write(
+        &path,
+        "firstName,\"Home, Address\"\r\nJohnDoe,\"Main \"\"Street\"\"\nNorth\"\r\n",
+    )
+    .unwrap();
+
+    let convert = |args: &[&str]| {
+        let output = Command::new("./target/debug/caseify")
+            .args(args)
+            .arg(&path)
+            .output()
+            .expect("Failed to run process");
+        String::from_utf8(output.stdout).expect("Invalid UTF-8 output")
+    };
+    assert_eq!(
+        convert(&["Snake", "--csv", "-f"]),
+        "first_name,\"home_address\"\r\njohn_doe,\"main_street_north\"\r\n"
+    );
+    assert_eq!(
+        convert(&["Snake", "--csv", "--header-only", "-f"]),
+        "first_name,\"home_address\"\r\nJohnDoe,\"Main \"\"Street\"\"\nNorth\"\r\n"
+    );
+    assert_eq!(
+        convert(&["Snake", "--csv", "--field", "2", "--jobs", "2", "-f"]),
+        "firstName,\"home_address\"\r\nJohnDoe,\"main_street_north\"\r\n"
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn match_pattern() {
+    let text = b"Please call getUserName() with the HTTPServer, not user_id (RFC 2616).\n";
+    let (identifiers, _) = run_with_stdin(&["Snake", "--match"], text);
+    assert_eq!(
+        identifiers,
+        "Please call get_user_name() with the http_server, not user_id (RFC 2616).\n"
+    );
+    let (glob, _) = run_with_stdin(&["Constant", "--match=*Name"], text);
+    assert_eq!(
+        glob,
+        "Please call GET_USER_NAME() with the HTTPServer, not user_id (RFC 2616).\n"
+    );
+    let (following, _) = run_with_stdin(&["Constant", "--match", "*Name"], text);
+    assert_eq!(following, glob);
+}
+
+#[test]
+fn json() {
+    let input =
+        b"{\"userName\": \"Ann Lee\",\n \"homeAddress\": {\"zipCode\": [1, {\"aB\": true}]}}\n";
+    let (preserved, _) = run_with_stdin(&["json", "Snake"], input);
+    assert_eq!(
+        preserved,
+        "{\"user_name\": \"Ann Lee\",\n \"home_address\": {\"zip_code\": [1, {\"a_b\": true}]}}\n"
+    );
+    let (compact, _) = run_with_stdin(
+        &["json", "Kebab", "--compact", "--depth=2", "--values"],
+        input,
+    );
+    assert_eq!(
+        compact,
+        "{\"user-name\":\"ann-lee\",\"home-address\":{\"zip-code\":[1,{\"aB\":true}]}}\n"
+    );
+    let dir = temp_dir("json");
+    let file = dir.join("config.json");
+    fs::write(&file, "{\"a_b\": {\"c_d\": []}}").unwrap();
+    let (pretty, _) = run_with_stdin(
+        &[
+            "json",
+            "Camel",
+            "--pretty",
+            "--exclude=*B",
+            file.to_str().unwrap(),
+        ],
+        b"",
+    );
+    assert_eq!(pretty, "{\n  \"aB\": {\n    \"cD\": []\n  }\n}\n");
+
+    let invalid_file = dir.join("invalid.json");
+    fs::write(&invalid_file, "[1,]").unwrap();
+    let output = Command::new("./target/debug/caseify")
+        .args(["json", "Snake"])
+        .arg(&invalid_file)
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(output.status.code(), Some(65));
+    assert_eq!(str::from_utf8(&output.stdout).unwrap(), "[1,");
+    assert!(
+        str::from_utf8(&output.stderr)
+            .unwrap()
+            .contains("line 1, column 4: unexpected `]`, expected a value")
+    );
+    fs::remove_dir_all(dir).unwrap();
+    let (_, invalid) = run_with_stdin(&["json", "Snake"], b"{\"a\": [1,\n2,,]}");
+    assert!(invalid.contains("line 2, column 3: unexpected `,`, expected a value"));
+}
+
+#[test]
+fn yaml_and_toml() {
+    let dir = temp_dir("config");
+    let yaml = dir.join("deployment.yml");
+    fs::write(
+        &yaml,
+        "apiVersion: v1 # keep: this\nspec:\n  - containerPort: 80\n    command: |\n      someKey: 1\n",
+    )
+    .unwrap();
+    let toml = dir.join("config.toml");
+    fs::write(&toml, "[serverConfig]\nlogLevel = \"debugMode\"\n").unwrap();
+
+    let output = Command::new("./target/debug/caseify")
+        .args(["yaml", "Snake"])
+        .arg(&yaml)
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(
+        str::from_utf8(&output.stdout).unwrap(),
+        "api_version: v1 # keep: this\nspec:\n  - container_port: 80\n    command: |\n      someKey: 1\n"
+    );
+    let status = Command::new("./target/debug/caseify")
+        .args(["toml", "Kebab", "-i"])
+        .arg(&toml)
+        .status()
+        .expect("Failed to run process");
+    assert!(status.success());
+    assert_eq!(
+        fs::read_to_string(&toml).unwrap(),
+        "[server-config]\nlog-level = \"debugMode\"\n"
+    );
+    let (stdin, _) = run_with_stdin(&["yaml", "Camel"], b"{max_retries: 3}\n");
+    assert_eq!(stdin, "{maxRetries: 3}\n");
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn env_and_ini() {
+    let (env, warnings) = run_with_stdin(
+        &["env", "Constant"],
+        b"# database.url=x\nexport database.url=\"a\nb=c\"\nDATABASE_URL=y\n",
+    );
+    assert_eq!(
+        env,
+        "# database.url=x\nexport DATABASE_URL=\"a\nb=c\"\nDATABASE_URL=y\n"
+    );
+    assert!(warnings.contains(
+        "Warning: <stdin>: line 4: `DATABASE_URL` and `database.url` on line 2 are both converted to `DATABASE_URL`"
+    ));
+
+    let dir = temp_dir("ini");
+    let path = dir.join("settings.ini");
+    fs::write(&path, "[mainServer]\nhttpPort = 80 ; web\n").unwrap();
+    let output = Command::new("./target/debug/caseify")
+        .args(["ini", "Kebab"])
+        .arg(&path)
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(
+        str::from_utf8(&output.stdout).unwrap(),
+        "[mainServer]\nhttp-port = 80 ; web\n"
+    );
+    assert!(output.stderr.is_empty());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn code() {
+    let (source, _) = run_with_stdin(
+        &[
+            "code",
+            "Camel",
+            "--lang",
+            "py",
+            "--kind=variable",
+            "--match=*_id",
+        ],
+        b"def get_user(user_id, name_id):\n    print('user_id')  # user_id\n    return user_id\n",
+    );
+    assert_eq!(
+        source,
+        "def get_user(userId, nameId):\n    print('user_id')  # user_id\n    return userId\n"
+    );
+
+    let dir = temp_dir("code");
+    let path = dir.join("main.rs");
+    fs::write(
+        &path,
+        "fn main() {\n    let line_count = 1;\n    dbg!(line_count);\n}\n",
+    )
+    .unwrap();
+    let output = Command::new("./target/debug/caseify")
+        .args(["code", "Camel", "-i"])
+        .arg(&path)
+        .output()
+        .expect("Failed to run process");
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "fn main() {\n    let lineCount = 1;\n    dbg!(lineCount);\n}\n"
+    );
+
+    let text = dir.join("notes.txt");
+    fs::write(&text, "user_id").unwrap();
+    let output = Command::new("./target/debug/caseify")
+        .args(["code", "Camel"])
+        .arg(&text)
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(output.status.code(), Some(65));
+    assert!(
+        str::from_utf8(&output.stderr)
+            .unwrap()
+            .contains("notes.txt: can't tell the language from the file extension, use `--lang`")
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn convention() {
+    let (names, _) = run_with_stdin(
+        &["convention", "go", "function"],
+        b"user_name\nHTTPServer\n",
+    );
+    assert_eq!(names, "userName\nHTTPServer\n");
+
+    let (line, _) = run_with_stdin(
+        &["convention", "Python", "enum-variant", "--match"],
+        b"Color.lightBlue\n",
+    );
+    assert_eq!(line, "Color.LIGHT_BLUE\n");
+}
+
+#[test]
+fn lint() {
+    let dir = temp_dir("lint");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let path = dir.join("src").join("userStore.py");
+    fs::write(
+        &path,
+        "MAX_SIZE = 10\n\nclass user_store:\n    def getName(self):\n        return 'getName'\n\nuser_store().getName()\n",
+    )
+    .unwrap();
+    fs::write(dir.join("src").join("notes.txt"), "getName").unwrap();
+    let output = Command::new("./target/debug/caseify")
+        .args(["lint", "py"])
+        .arg(dir.join("src"))
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(output.status.code(), Some(1));
+    let file = path.display();
+    assert_eq!(
+        str::from_utf8(&output.stdout).unwrap(),
+        format!(
+            "{file}: file name userStore -> user_store (Snake case)\n{file}:3:7: type user_store -> UserStore (Pascal case)\n{file}:4:9: function getName -> get_name (Snake case)\n"
+        )
+    );
+    assert!(
+        str::from_utf8(&output.stderr)
+            .unwrap()
+            .contains("3 names don't follow the Python convention")
+    );
+
+    let source = dir.join("src").join("store.rs");
+    fs::write(
+        &source,
+        "pub const maxSize: usize = 10;\n\nstruct user_store;\n\nfn getName() -> &'static str {\n    \"getName\"\n}\n\npub fn Run() {\n    let _ = (user_store, getName());\n}\n",
+    )
+    .unwrap();
+    let output = Command::new("./target/debug/caseify")
+        .args(["lint", "rust", "--fix"])
+        .arg(&source)
+        .output()
+        .expect("Failed to run process");
+    assert_eq!(output.status.code(), Some(1));
+    let file = source.display();
+    assert_eq!(
+        str::from_utf8(&output.stdout).unwrap(),
+        format!(
+            "{file}:1:11: constant maxSize -> MAX_SIZE (Constant case)\n{file}:3:8: type user_store -> UserStore (Pascal case, fixed)\n{file}:5:4: function getName -> get_name (Snake case, fixed)\n{file}:9:8: function Run -> run (Snake case)\n"
+        )
+    );
+    assert_eq!(
+        fs::read_to_string(&source).unwrap(),
+        "pub const maxSize: usize = 10;\n\nstruct UserStore;\n\nfn get_name() -> &'static str {\n    \"getName\"\n}\n\npub fn Run() {\n    let _ = (UserStore, get_name());\n}\n"
+    );
+    assert!(
+        str::from_utf8(&output.stderr)
+            .unwrap()
+            .contains("2 names don't follow the Rust convention")
+    );
+
+    let server = dir.join("server.go");
+    fs::write(
+        &server,
+        "package server\n\nfunc (s *Server) ServeHTTP(userID string) {}\n",
+    )
+    .unwrap();
+    let output = Command::new("./target/debug/caseify")
+        .args(["lint", "go"])
+        .arg(&server)
+        .output()
+        .expect("Failed to run process");
+    assert!(output.status.success());
+
+    fs::remove_dir_all(dir).unwrap();
+}
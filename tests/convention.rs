@@ -0,0 +1,98 @@
+use caseify::{Case, Convention, IdentKind};
+
+#[test]
+fn cases() {
+    assert_eq!(Convention::Rust.case(IdentKind::Type), Case::Pascal);
+    assert_eq!(Convention::Rust.case(IdentKind::EnumVariant), Case::Pascal);
+    assert_eq!(Convention::Rust.case(IdentKind::Constant), Case::Constant);
+    assert_eq!(
+        Convention::Python.case(IdentKind::EnumVariant),
+        Case::Constant
+    );
+    assert_eq!(Convention::Python.case(IdentKind::Function), Case::Snake);
+    assert_eq!(Convention::Java.case(IdentKind::Module), Case::Dot);
+    assert_eq!(
+        Convention::JavaScript.case(IdentKind::FileName),
+        Case::Kebab
+    );
+    assert_eq!(Convention::CSharp.case(IdentKind::Function), Case::Pascal);
+    assert_eq!(Convention::CSharp.case(IdentKind::Local), Case::Camel);
+    assert_eq!(Convention::Swift.case(IdentKind::EnumVariant), Case::Camel);
+    assert_eq!(Convention::Sql.case(IdentKind::Type), Case::Snake);
+    assert_eq!(Convention::C.case(IdentKind::Type), Case::Snake);
+    assert_eq!(Convention::C.case(IdentKind::EnumVariant), Case::Constant);
+    assert_eq!(Convention::Go.case(IdentKind::Function), Case::Pascal);
+    assert_eq!(
+        Convention::Go.unexported_case(IdentKind::Function),
+        Case::Camel
+    );
+    assert_eq!(
+        Convention::Rust.unexported_case(IdentKind::Type),
+        Case::Pascal
+    );
+}
+
+#[test]
+fn convert() {
+    assert_eq!(
+        Convention::Rust.convert(IdentKind::Function, "getUserName"),
+        "get_user_name"
+    );
+    assert_eq!(
+        Convention::Python.convert(IdentKind::Function, "_privateHelper"),
+        "_private_helper"
+    );
+    assert_eq!(
+        Convention::Kotlin.convert(IdentKind::Constant, "maxSize"),
+        "MAX_SIZE"
+    );
+    assert_eq!(
+        Convention::Go.convert(IdentKind::Function, "ServeHTTP"),
+        "ServeHTTP"
+    );
+    assert_eq!(Convention::Go.convert(IdentKind::Local, "userID"), "userID");
+    assert_eq!(
+        Convention::Go.convert(IdentKind::Type, "HTTPServer"),
+        "HTTPServer"
+    );
+    assert_eq!(
+        Convention::Go.convert(IdentKind::Function, "parse_URL"),
+        "parseUrl"
+    );
+    assert_eq!(
+        Convention::Go.convert(IdentKind::Function, "parse_url"),
+        "parseUrl"
+    );
+    assert_eq!(
+        Convention::Go.case_for(IdentKind::Type, "userStore"),
+        Case::Camel
+    );
+    assert_eq!(
+        Convention::Go.convert(IdentKind::Module, "http_util"),
+        "httputil"
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!("rust".parse(), Ok(Convention::Rust));
+    assert_eq!("PEP8".parse(), Ok(Convention::Python));
+    assert_eq!("C#".parse(), Ok(Convention::CSharp));
+    assert_eq!("TypeScript".parse(), Ok(Convention::JavaScript));
+    assert_eq!(
+        "cobol".parse::<Convention>().map_err(|err| err.to_string()),
+        Err("cobol isn't a valid convention".to_owned())
+    );
+    assert_eq!(Convention::JavaScript.to_string(), "JavaScript");
+    assert_eq!(Convention::ALL.len(), 10);
+
+    assert_eq!("enum-variant".parse(), Ok(IdentKind::EnumVariant));
+    assert_eq!("FileName".parse(), Ok(IdentKind::FileName));
+    assert_eq!("fn".parse(), Ok(IdentKind::Function));
+    assert_eq!("variable".parse(), Ok(IdentKind::Local));
+    assert_eq!(
+        "macro".parse::<IdentKind>().map_err(|err| err.to_string()),
+        Err("macro isn't a valid identifier kind".to_owned())
+    );
+    assert_eq!(IdentKind::EnumVariant.to_string(), "enum variant");
+}
@@ -0,0 +1,50 @@
+use caseify::{Case, CaseCycle};
+
+#[test]
+fn next_prev() {
+    assert_eq!(Case::Snake.next(), Case::Camel);
+    assert_eq!(Case::Camel.next(), Case::Pascal);
+    assert_eq!(Case::CssVar.next(), Case::Snake);
+    assert_eq!(Case::Snake.prev(), Case::CssVar);
+    assert_eq!(Case::Pascal.prev(), Case::Camel);
+}
+
+#[test]
+fn custom_order() {
+    let cycle = CaseCycle::new(vec![Case::Snake, Case::Constant]).unwrap();
+    assert_eq!(cycle.next(Case::Snake), Case::Constant);
+    assert_eq!(cycle.next(Case::Constant), Case::Snake);
+    assert_eq!(cycle.next(Case::Dot), Case::Snake);
+    assert_eq!(cycle.prev(Case::Dot), Case::Constant);
+    assert_eq!(CaseCycle::new(vec![]), None);
+}
+
+#[test]
+fn detect() {
+    assert_eq!(Case::detect("fooBar"), vec![Case::Camel]);
+    assert_eq!(
+        Case::detect("foo"),
+        vec![Case::Camel, Case::Dot, Case::Kebab, Case::Snake]
+    );
+    assert!(Case::detect("foo bar").is_empty());
+}
+
+#[test]
+fn cycle_input() {
+    let cycle = CaseCycle::default();
+    assert_eq!(cycle.cycle_next("foo_bar"), "fooBar");
+    assert_eq!(cycle.cycle_next("fooBar"), "FooBar");
+    assert_eq!(cycle.cycle_next("FooBar"), "foo-bar");
+    assert_eq!(cycle.cycle_next("foo-bar"), "FOO_BAR");
+    assert_eq!(cycle.cycle_prev("FOO_BAR"), "foo-bar");
+    assert_eq!(cycle.cycle_prev("foo_bar"), "--foo-bar");
+
+    // `foo` is both `snake_case` and `camelCase`, so `camelCase` is skipped.
+    assert_eq!(cycle.cycle_next("foo"), "Foo");
+
+    // Inputs in none of the cases are converted to the first case.
+    let cycle = CaseCycle::new(vec![Case::Camel, Case::Constant]).unwrap();
+    assert_eq!(cycle.cycle_next("foo bar"), "fooBar");
+    assert_eq!(cycle.cycle_next("fooBar"), "FOO_BAR");
+    assert_eq!(cycle.cycle_next("FOO_BAR"), "fooBar");
+}
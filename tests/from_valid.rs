@@ -6,6 +6,7 @@ struct CaseTestEntry {
     snake: &'static str,
     constant: &'static str,
     kebab: &'static str,
+    css_var: &'static str,
     capitalised: &'static str,
     sentence: &'static str,
     dot: &'static str,
@@ -18,6 +19,7 @@ impl CaseTestEntry {
         assert_eq!(convert(self.snake), expected);
         assert_eq!(convert(self.constant), expected);
         assert_eq!(convert(self.kebab), expected);
+        assert_eq!(convert(self.css_var), expected);
         assert_eq!(convert(self.capitalised), expected);
         assert_eq!(convert(self.sentence), expected);
         assert_eq!(convert(self.dot), expected);
@@ -29,6 +31,7 @@ impl CaseTestEntry {
         self.test_output(Caseify::to_snake_case, self.snake);
         self.test_output(Caseify::to_constant_case, self.constant);
         self.test_output(Caseify::to_kebab_case, self.kebab);
+        self.test_output(Caseify::to_css_var_case, self.css_var);
         self.test_output(Caseify::to_capitalised_case, self.capitalised);
         self.test_output(Caseify::to_sentence_case, self.sentence);
         self.test_output(Caseify::to_dot_case, self.dot);
@@ -43,6 +46,7 @@ fn from_valid() {
         snake: "some_case_example",
         constant: "SOME_CASE_EXAMPLE",
         kebab: "some-case-example",
+        css_var: "--some-case-example",
         capitalised: "Some Case Example",
         sentence: "Some case example",
         dot: "some.case.example",
@@ -55,6 +59,7 @@ fn from_valid() {
         snake: "another_example",
         constant: "ANOTHER_EXAMPLE",
         kebab: "another-example",
+        css_var: "--another-example",
         capitalised: "Another Example",
         sentence: "Another example",
         dot: "another.example",
@@ -67,6 +72,7 @@ fn from_valid() {
         snake: "yet_another_example",
         constant: "YET_ANOTHER_EXAMPLE",
         kebab: "yet-another-example",
+        css_var: "--yet-another-example",
         capitalised: "Yet Another Example",
         sentence: "Yet another example",
         dot: "yet.another.example",
@@ -79,6 +85,7 @@ fn from_valid() {
         snake: "example_with_numbers_123",
         constant: "EXAMPLE_WITH_NUMBERS_123",
         kebab: "example-with-numbers-123",
+        css_var: "--example-with-numbers-123",
         capitalised: "Example With Numbers 123",
         sentence: "Example with numbers 123",
         dot: "example.with.numbers.123",
@@ -7,6 +7,7 @@ struct OneWayCaseTestEntry {
     snake: &'static str,
     constant: &'static str,
     kebab: &'static str,
+    css_var: &'static str,
     capitalised: &'static str,
     sentence: &'static str,
     dot: &'static str,
@@ -18,6 +19,7 @@ fn test(entry: &OneWayCaseTestEntry) {
     assert_eq!(entry.input.to_snake_case(), entry.snake);
     assert_eq!(entry.input.to_constant_case(), entry.constant);
     assert_eq!(entry.input.to_kebab_case(), entry.kebab);
+    assert_eq!(entry.input.to_css_var_case(), entry.css_var);
     assert_eq!(entry.input.to_capitalised_case(), entry.capitalised);
     assert_eq!(entry.input.to_sentence_case(), entry.sentence);
     assert_eq!(entry.input.to_dot_case(), entry.dot);
@@ -32,6 +34,7 @@ fn single_char() {
         snake: "a_b",
         constant: "A_B",
         kebab: "a-b",
+        css_var: "--a-b",
         capitalised: "A B",
         sentence: "A b",
         dot: "a.b",
@@ -44,6 +47,7 @@ fn single_char() {
         snake: "a_b",
         constant: "A_B",
         kebab: "a-b",
+        css_var: "--a-b",
         capitalised: "A B",
         sentence: "A b",
         dot: "a.b",
@@ -56,6 +60,7 @@ fn single_char() {
         snake: "a",
         constant: "A",
         kebab: "a",
+        css_var: "--a",
         capitalised: "A",
         sentence: "A",
         dot: "a",
@@ -68,8 +73,21 @@ fn single_char() {
         snake: "x_y_z",
         constant: "X_Y_Z",
         kebab: "x-y-z",
+        css_var: "--x-y-z",
         capitalised: "X Y Z",
         sentence: "X y z",
         dot: "x.y.z",
     });
+    test(&OneWayCaseTestEntry {
+        input: "--primary-color",
+        camel: "primaryColor",
+        pascal: "PrimaryColor",
+        snake: "primary_color",
+        constant: "PRIMARY_COLOR",
+        kebab: "primary-color",
+        css_var: "--primary-color",
+        capitalised: "Primary Color",
+        sentence: "Primary color",
+        dot: "primary.color",
+    });
 }
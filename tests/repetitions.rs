@@ -23,3 +23,20 @@ fn repetitions() {
     assert_eq!("linux    _Kernel".to_camel_case(), "linuxKernel");
     assert_eq!("some--weird___input".to_pascal_case(), "SomeWeirdInput");
 }
+
+#[test]
+fn surrounding_symbols() {
+    // Leading symbols are dropped, in every case
+    assert_eq!("_private".to_snake_case(), "private");
+    assert_eq!("_private".to_camel_case(), "private");
+    assert_eq!("__init__".to_pascal_case(), "Init");
+    assert_eq!("$element".to_constant_case(), "ELEMENT");
+    assert_eq!("--main-color".to_kebab_case(), "main-color");
+    assert_eq!("--main-color".to_css_var_case(), "--main-color");
+    assert_eq!("...".to_snake_case(), "");
+
+    // Inner symbols separate words and trailing ones are dropped, as before
+    assert_eq!("foo_$bar".to_snake_case(), "foo_bar");
+    assert_eq!("foo.bar!".to_camel_case(), "fooBar");
+    assert_eq!("value_".to_dot_case(), "value");
+}
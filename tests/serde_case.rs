@@ -0,0 +1,30 @@
+#![cfg(feature = "serde")]
+
+use caseify::Case;
+
+#[test]
+fn serialize() {
+    assert_eq!(serde_json::to_string(&Case::Snake).unwrap(), r#""Snake""#);
+    assert_eq!(
+        serde_json::to_string(&[Case::CssVar, Case::Constant]).unwrap(),
+        r#"["CssVar","Constant"]"#
+    );
+}
+
+#[test]
+fn deserialize() {
+    for case in Case::iter() {
+        let json = serde_json::to_string(&case).unwrap();
+        assert_eq!(serde_json::from_str::<Case>(&json).unwrap(), case);
+    }
+    assert_eq!(
+        serde_json::from_str::<Vec<Case>>(r#"["snake_case", "SCREAMING_SNAKE", "kebab"]"#).unwrap(),
+        [Case::Snake, Case::Constant, Case::Kebab]
+    );
+    let err = serde_json::from_str::<Case>(r#""blob""#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "blob isn't a valid case at line 1 column 6"
+    );
+    assert!(serde_json::from_str::<Case>("1").is_err());
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use caseify::Case;
+use caseify::serde::{AnyCaseKeys, RenameKeys};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Address {
+    street_name: String,
+    zip_code: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Role {
+    Admin,
+    Guest { expires_at: u64 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct User {
+    first_name: String,
+    home_address: Option<Address>,
+    previous_addresses: Vec<Address>,
+    extra_fields: BTreeMap<String, String>,
+    user_role: Role,
+}
+
+fn user() -> User {
+    User {
+        first_name: "some_name".to_owned(),
+        home_address: Some(Address {
+            street_name: "main_street".to_owned(),
+            zip_code: 1234,
+        }),
+        previous_addresses: vec![Address {
+            street_name: "old_street".to_owned(),
+            zip_code: 5678,
+        }],
+        extra_fields: BTreeMap::from([("favouriteColour".to_owned(), "blue_green".to_owned())]),
+        user_role: Role::Guest { expires_at: 42 },
+    }
+}
+
+const CAMEL: &str = r#"{"firstName":"some_name","homeAddress":{"streetName":"main_street","zipCode":1234},"previousAddresses":[{"streetName":"old_street","zipCode":5678}],"extraFields":{"favouriteColour":"blue_green"},"userRole":{"Guest":{"expiresAt":42}}}"#;
+
+#[test]
+fn serialize() {
+    let user = user();
+    assert_eq!(
+        serde_json::to_string(&RenameKeys(Case::Camel, &user)).unwrap(),
+        CAMEL
+    );
+    assert_eq!(
+        serde_json::to_string(&RenameKeys(Case::Constant, &user)).unwrap(),
+        r#"{"FIRST_NAME":"some_name","HOME_ADDRESS":{"STREET_NAME":"main_street","ZIP_CODE":1234},"PREVIOUS_ADDRESSES":[{"STREET_NAME":"old_street","ZIP_CODE":5678}],"EXTRA_FIELDS":{"FAVOURITE_COLOUR":"blue_green"},"USER_ROLE":{"Guest":{"EXPIRES_AT":42}}}"#
+    );
+}
+
+#[test]
+fn serialize_unit_variant_and_none() {
+    let user = User {
+        home_address: None,
+        user_role: Role::Admin,
+        ..user()
+    };
+    let json = serde_json::to_string(&RenameKeys(Case::Kebab, &user)).unwrap();
+    assert!(json.contains(r#""home-address":null"#), "{json}");
+    assert!(json.contains(r#""user-role":"Admin""#), "{json}");
+}
+
+#[test]
+fn deserialize() {
+    let AnyCaseKeys(user) = serde_json::from_str::<AnyCaseKeys<User>>(CAMEL).unwrap();
+    let mut expected = self::user();
+    assert_eq!(user, expected);
+
+    let kebab = serde_json::to_string(&RenameKeys(Case::Kebab, &expected)).unwrap();
+    let AnyCaseKeys(user) = serde_json::from_str::<AnyCaseKeys<User>>(&kebab).unwrap();
+    // Map keys can't be matched against fields, so they keep the case of the input.
+    expected.extra_fields =
+        BTreeMap::from([("favourite-colour".to_owned(), "blue_green".to_owned())]);
+    assert_eq!(user, expected);
+}
+
+#[test]
+fn deserialize_unknown_field() {
+    let err =
+        serde_json::from_str::<AnyCaseKeys<Address>>(r#"{"streetName":"a","zip":1}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("missing field `zip_code`"),
+        "{err}"
+    );
+}